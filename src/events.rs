@@ -1,6 +1,6 @@
-use bevy::prelude::{Event, Vec2};
+use bevy::prelude::{Entity, Event, Vec2};
 
-use crate::gameplay::components::{Damage, Shooter, ShotSpeed};
+use crate::gameplay::components::{Critical, Damage, Shooter, ShotSpeed};
 
 #[derive(Event)]
 pub struct ShootEvent {
@@ -9,4 +9,17 @@ pub struct ShootEvent {
     pub damage: Damage,
     pub shooter: Shooter,
     pub bullet_speed: ShotSpeed,
+    pub critical: Critical,
 }
+
+#[derive(Event)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub position: Vec2,
+    pub amount: i32,
+    pub dealt_by: Shooter,
+    pub critical: Critical,
+}
+
+#[derive(Event)]
+pub struct NewRunEvent;
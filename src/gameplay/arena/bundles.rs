@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::RemoveOnReset;
+
+use super::components::{Obstacle, ObstacleKind, Solid, Wall};
+
+#[derive(Bundle)]
+pub struct WallBundle {
+    wall: Wall,
+    solid: Solid,
+    sprite: SpriteBundle,
+    remove_on_reset: RemoveOnReset,
+}
+
+impl WallBundle {
+    pub fn new(center: Vec2, size: Vec2) -> Self {
+        Self {
+            wall: Wall,
+            solid: Solid,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: Color::DARK_GRAY,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_translation(center.extend(-0.5)),
+                ..default()
+            },
+            remove_on_reset: RemoveOnReset,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ObstacleBundle {
+    obstacle: Obstacle,
+    solid: Solid,
+    sprite: SpriteBundle,
+    remove_on_reset: RemoveOnReset,
+}
+
+impl ObstacleBundle {
+    pub fn new(kind: ObstacleKind, center: Vec2) -> Self {
+        Self {
+            obstacle: Obstacle,
+            solid: Solid,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: kind.color(),
+                    custom_size: Some(kind.size()),
+                    ..default()
+                },
+                transform: Transform::from_translation(center.extend(-0.5)),
+                ..default()
+            },
+            remove_on_reset: RemoveOnReset,
+        }
+    }
+}
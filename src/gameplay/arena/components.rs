@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Blocks movement, bullets and line of sight; the sprite size is its footprint.
+#[derive(Component)]
+pub struct Solid;
+
+/// One side of the arena border.
+#[derive(Component)]
+pub struct Wall;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ObstacleKind {
+    Pillar,
+    Crate,
+}
+
+impl ObstacleKind {
+    pub fn size(&self) -> Vec2 {
+        match self {
+            ObstacleKind::Pillar => Vec2::splat(70.),
+            ObstacleKind::Crate => Vec2::new(110., 60.),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            ObstacleKind::Pillar => Color::rgb(0.35, 0.35, 0.4),
+            ObstacleKind::Crate => Color::rgb(0.45, 0.3, 0.15),
+        }
+    }
+}
+
+/// A pillar or crate placed by the layout generator.
+#[derive(Component)]
+pub struct Obstacle;
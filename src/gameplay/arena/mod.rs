@@ -0,0 +1,31 @@
+use bevy::{prelude::*, transform::TransformSystem};
+
+use self::{
+    resources::ArenaConfig,
+    systems::{block_movers, bullets_hit_solids, spawn_arena},
+};
+
+use crate::gameplay::{states::GameState, system_sets::RunSet};
+
+pub mod bundles;
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ArenaConfig>()
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (spawn_arena).in_set(RunSet::Setup),
+            )
+            .add_systems(
+                PostUpdate,
+                (block_movers, bullets_hit_solids)
+                    .before(TransformSystem::TransformPropagate)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
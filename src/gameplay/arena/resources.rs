@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Clone)]
+pub struct ArenaConfig {
+    /// Half the width and height of the playable area, centered on the origin.
+    pub half_extents: Vec2,
+    pub wall_thickness: f32,
+    pub obstacles: usize,
+    /// Obstacles stay at least this far from the player's starting point.
+    pub clear_radius: f32,
+    /// Bullets bounce off walls and obstacles instead of breaking on them.
+    pub ricochet: bool,
+}
+
+impl ArenaConfig {
+    /// Pulls `point` inside the walls, keeping `margin` away from them.
+    pub fn clamp(&self, point: Vec2, margin: f32) -> Vec2 {
+        let limit = (self.half_extents - margin).max(Vec2::ZERO);
+        point.clamp(-limit, limit)
+    }
+}
+
+impl Default for ArenaConfig {
+    fn default() -> Self {
+        Self {
+            half_extents: Vec2::new(1600., 1200.),
+            wall_thickness: 40.,
+            obstacles: 24,
+            clear_radius: 250.,
+            ricochet: true,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use bevy::{
+    math::bounding::{Aabb2d, BoundingVolume, IntersectsVolume, RayCast2d},
+    prelude::*,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::gameplay::{
+    components::MyDirection, enemies::components::Enemy, player::components::Player,
+    projectiles::components::Bullet, resources::RunSeed,
+};
+
+use super::{
+    bundles::{ObstacleBundle, WallBundle},
+    components::{ObstacleKind, Solid},
+    resources::ArenaConfig,
+};
+
+/// Free space kept between obstacles and around the walls, so nothing gets boxed in.
+const OBSTACLE_GAP: f32 = 80.;
+const PLACEMENT_ATTEMPTS: usize = 20;
+
+/// Scatters up to `config.obstacles` non-overlapping pillars and crates, clear of
+/// the walls and the starting point.
+pub fn generate_layout(config: &ArenaConfig, rng: &mut impl Rng) -> Vec<(ObstacleKind, Vec2)> {
+    let mut placed: Vec<(ObstacleKind, Vec2)> = Vec::with_capacity(config.obstacles);
+    for _ in 0..config.obstacles * PLACEMENT_ATTEMPTS {
+        if placed.len() >= config.obstacles {
+            break;
+        }
+        let kind = if rng.gen_bool(0.5) {
+            ObstacleKind::Pillar
+        } else {
+            ObstacleKind::Crate
+        };
+        let limit = config.half_extents - kind.size() / 2. - OBSTACLE_GAP;
+        if limit.min_element() <= 0. {
+            break;
+        }
+        let center = Vec2::new(
+            rng.gen_range(-limit.x..limit.x),
+            rng.gen_range(-limit.y..limit.y),
+        );
+        if center.length() < config.clear_radius {
+            continue;
+        }
+        let footprint = Aabb2d::new(center, (kind.size() + OBSTACLE_GAP) / 2.);
+        let blocked = placed.iter().any(|(other, other_center)| {
+            footprint.intersects(&Aabb2d::new(*other_center, other.size() / 2.))
+        });
+        if !blocked {
+            placed.push((kind, center));
+        }
+    }
+    placed
+}
+
+pub fn spawn_arena(config: Res<ArenaConfig>, seed: Res<RunSeed>, mut commands: Commands) {
+    let half = config.half_extents;
+    let thickness = config.wall_thickness;
+    let horizontal = Vec2::new((half.x + thickness) * 2., thickness);
+    let vertical = Vec2::new(thickness, half.y * 2.);
+    let offset = half + thickness / 2.;
+    commands.spawn_batch([
+        WallBundle::new(Vec2::new(0., offset.y), horizontal),
+        WallBundle::new(Vec2::new(0., -offset.y), horizontal),
+        WallBundle::new(Vec2::new(offset.x, 0.), vertical),
+        WallBundle::new(Vec2::new(-offset.x, 0.), vertical),
+    ]);
+    commands.spawn_batch(
+        generate_layout(&config, &mut StdRng::seed_from_u64(seed.0))
+            .into_iter()
+            .map(|(kind, center)| ObstacleBundle::new(kind, center)),
+    );
+}
+
+pub fn solid_box(transform: &Transform, sprite: &Sprite) -> Aabb2d {
+    Aabb2d::new(
+        transform.translation.xy(),
+        sprite.custom_size.unwrap() * 0.5,
+    )
+}
+
+/// Whether nothing solid stands between `from` and `to`.
+pub fn line_of_sight(from: Vec2, to: Vec2, solids: &[Aabb2d]) -> bool {
+    let offset = to - from;
+    let Ok(direction) = Direction2d::new(offset) else {
+        return true;
+    };
+    let ray = RayCast2d::new(from, direction, offset.length());
+    !solids
+        .iter()
+        .any(|solid| ray.aabb_intersection_at(solid).is_some())
+}
+
+/// The shortest push that moves `mover` out of `solid`, if they overlap.
+fn separation(mover: &Aabb2d, solid: &Aabb2d) -> Option<Vec2> {
+    let overlap = mover.max.min(solid.max) - mover.min.max(solid.min);
+    if overlap.x <= 0. || overlap.y <= 0. {
+        return None;
+    }
+    let away = mover.center() - solid.center();
+    Some(if overlap.x < overlap.y {
+        Vec2::new(overlap.x.copysign(away.x), 0.)
+    } else {
+        Vec2::new(0., overlap.y.copysign(away.y))
+    })
+}
+
+/// Runs after all movement for the frame, so nothing is drawn inside a wall.
+pub fn block_movers(
+    mut q_movers: Query<
+        (&mut Transform, &Sprite),
+        (Or<(With<Player>, With<Enemy>)>, Without<Solid>),
+    >,
+    q_solids: Query<(&Transform, &Sprite), With<Solid>>,
+) {
+    let solids: Vec<Aabb2d> = q_solids
+        .iter()
+        .map(|(transform, sprite)| solid_box(transform, sprite))
+        .collect();
+    q_movers
+        .iter_mut()
+        .for_each(|(mut mover_tr, mover_sprite)| {
+            let half_size = mover_sprite.custom_size.unwrap() * 0.5;
+            let mut center = mover_tr.translation.xy();
+            solids.iter().for_each(|solid| {
+                if let Some(push) = separation(&Aabb2d::new(center, half_size), solid) {
+                    center += push;
+                }
+            });
+            if center != mover_tr.translation.xy() {
+                mover_tr.translation = center.extend(mover_tr.translation.z);
+            }
+        });
+}
+
+pub fn bullets_hit_solids(
+    mut q_bullets: Query<
+        (&mut Transform, &Sprite, &mut MyDirection, Entity),
+        (With<Bullet>, Without<Solid>),
+    >,
+    q_solids: Query<(&Transform, &Sprite), With<Solid>>,
+    config: Res<ArenaConfig>,
+    mut commands: Commands,
+) {
+    let solids: Vec<Aabb2d> = q_solids
+        .iter()
+        .map(|(transform, sprite)| solid_box(transform, sprite))
+        .collect();
+    q_bullets.iter_mut().for_each(
+        |(mut bullet_tr, bullet_sprite, mut bullet_dir, bullet_entity)| {
+            let bullet_box = solid_box(&bullet_tr, bullet_sprite);
+            let Some(push) = solids
+                .iter()
+                .find_map(|solid| separation(&bullet_box, solid))
+            else {
+                return;
+            };
+            if !config.ricochet {
+                commands.entity(bullet_entity).despawn();
+                return;
+            }
+            bullet_tr.translation += push.extend(0.);
+            if push.x != 0. {
+                bullet_dir.0.x = -bullet_dir.0.x;
+            } else {
+                bullet_dir.0.y = -bullet_dir.0.y;
+            }
+        },
+    );
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::RemoveOnReset;
+
+use super::components::Chunk;
+
+#[derive(Bundle)]
+pub struct ChunkBundle {
+    chunk: Chunk,
+    sprite: SpriteBundle,
+    remove_on_reset: RemoveOnReset,
+}
+
+impl ChunkBundle {
+    pub fn new(coord: IVec2, size: f32, color: Color, z: f32) -> Self {
+        Self {
+            chunk: Chunk(coord),
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                transform: Transform::from_translation(((coord.as_vec2() + 0.5) * size).extend(z)),
+                ..default()
+            },
+            remove_on_reset: RemoveOnReset,
+        }
+    }
+}
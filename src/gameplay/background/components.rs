@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// A square of ground, identified by its grid coordinate; decals and grid lines are
+/// its children.
+#[derive(Component)]
+pub struct Chunk(pub IVec2);
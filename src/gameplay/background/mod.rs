@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+use self::systems::stream_chunks;
+
+pub mod bundles;
+pub mod components;
+pub mod systems;
+
+pub struct BackgroundPlugin;
+
+impl Plugin for BackgroundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (stream_chunks).run_if(in_state(GameState::Running)));
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::gameplay::{components::MainCamera, resources::RunSeed};
+
+use super::{bundles::ChunkBundle, components::Chunk};
+
+pub const CHUNK_SIZE: f32 = 512.;
+const TILES_PER_CHUNK: u32 = 4;
+const GRID_LINE_WIDTH: f32 = 2.;
+const MAX_DECALS: u32 = 6;
+/// Chunks this many rings past the visible ones are kept, so walking back and forth
+/// along a chunk border doesn't rebuild it every frame.
+const UNLOAD_MARGIN: i32 = 1;
+const BACKGROUND_Z: f32 = -10.;
+const GROUND_COLOR: Vec3 = Vec3::new(0.13, 0.14, 0.16);
+
+pub fn chunk_of(position: Vec2) -> IVec2 {
+    (position / CHUNK_SIZE).floor().as_ivec2()
+}
+
+/// Every chunk gets its own stream, so it looks the same whenever it is rebuilt.
+fn chunk_rng(seed: u64, coord: IVec2) -> StdRng {
+    let mixed = seed
+        ^ (coord.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (coord.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    StdRng::seed_from_u64(mixed)
+}
+
+fn shade(offset: f32, alpha: f32) -> Color {
+    let rgb = GROUND_COLOR + offset;
+    Color::rgba(rgb.x, rgb.y, rgb.z, alpha)
+}
+
+fn spawn_chunk(commands: &mut Commands, seed: u64, coord: IVec2) {
+    let mut rng = chunk_rng(seed, coord);
+    let color = shade(rng.gen_range(-0.015..0.015), 1.);
+    let tile = CHUNK_SIZE / TILES_PER_CHUNK as f32;
+    commands
+        .spawn(ChunkBundle::new(coord, CHUNK_SIZE, color, BACKGROUND_Z))
+        .with_children(|parent| {
+            // Lines along the left and bottom edge of every tile; the neighbours
+            // close the grid on the other sides
+            (0..TILES_PER_CHUNK).for_each(|i| {
+                let offset = i as f32 * tile - CHUNK_SIZE / 2.;
+                [
+                    (
+                        Vec2::new(offset, 0.),
+                        Vec2::new(GRID_LINE_WIDTH, CHUNK_SIZE),
+                    ),
+                    (
+                        Vec2::new(0., offset),
+                        Vec2::new(CHUNK_SIZE, GRID_LINE_WIDTH),
+                    ),
+                ]
+                .into_iter()
+                .for_each(|(position, size)| {
+                    parent.spawn(SpriteBundle {
+                        sprite: Sprite {
+                            color: shade(0.04, 1.),
+                            custom_size: Some(size),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(position.extend(0.1)),
+                        ..default()
+                    });
+                });
+            });
+            (0..rng.gen_range(0..=MAX_DECALS)).for_each(|_| {
+                let half = CHUNK_SIZE / 2.;
+                let position = Vec2::new(rng.gen_range(-half..half), rng.gen_range(-half..half));
+                let size = Vec2::new(rng.gen_range(6. ..24.), rng.gen_range(6. ..24.));
+                parent.spawn(SpriteBundle {
+                    sprite: Sprite {
+                        color: shade(rng.gen_range(-0.05..0.08), 0.8),
+                        custom_size: Some(size),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(position.extend(0.2)).with_rotation(
+                        Quat::from_rotation_z(rng.gen_range(0. ..std::f32::consts::TAU)),
+                    ),
+                    ..default()
+                });
+            });
+        });
+}
+
+/// Fills the view with ground chunks and drops the ones the camera left behind.
+pub fn stream_chunks(
+    q_camera: Query<&Transform, With<MainCamera>>,
+    q_windows: Query<&Window, With<PrimaryWindow>>,
+    q_chunks: Query<(&Chunk, Entity)>,
+    seed: Res<RunSeed>,
+    mut commands: Commands,
+) {
+    let (Ok(camera_tr), Ok(window)) = (q_camera.get_single(), q_windows.get_single()) else {
+        return;
+    };
+    let center = chunk_of(camera_tr.translation.xy());
+    let reach = (Vec2::new(window.width(), window.height()) / 2. / CHUNK_SIZE)
+        .ceil()
+        .as_ivec2();
+
+    let mut loaded = HashSet::new();
+    q_chunks.iter().for_each(|(chunk, entity)| {
+        let distance = (chunk.0 - center).abs();
+        if distance.cmpgt(reach + UNLOAD_MARGIN).any() {
+            commands.entity(entity).despawn_recursive();
+        } else {
+            loaded.insert(chunk.0);
+        }
+    });
+    (-reach.x..=reach.x).for_each(|x| {
+        (-reach.y..=reach.y).for_each(|y| {
+            let coord = center + IVec2::new(x, y);
+            if !loaded.contains(&coord) {
+                spawn_chunk(&mut commands, seed.0, coord);
+            }
+        });
+    });
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::components::{Damage, MainCamera, ReloadStopwatch, ReloadTime, ShotSpeed};
+use super::components::{CritChance, Damage, MainCamera, ReloadStopwatch, ReloadTime, ShotSpeed};
 
 #[derive(Bundle)]
 pub struct ShooterBundle {
@@ -8,6 +8,7 @@ pub struct ShooterBundle {
     pub since_last_reload: ReloadStopwatch,
     pub reload_time: ReloadTime,
     pub shot_speed: ShotSpeed,
+    pub crit_chance: CritChance,
 }
 
 #[derive(Bundle)]
@@ -11,6 +11,12 @@ pub struct Speed(pub f32);
 #[derive(Component, Clone)]
 pub struct ShotSpeed(pub f32);
 
+#[derive(Component, Clone)]
+pub struct CritChance(pub f32);
+
+#[derive(Component, Clone, Copy, Default)]
+pub struct Critical(pub bool);
+
 #[derive(Component, Clone)]
 pub struct ReloadStopwatch(pub Stopwatch);
 
@@ -52,12 +58,64 @@ pub struct HitBlinkTimer {
 #[derive(Component, Clone, Copy)]
 pub struct Health(pub i32);
 
+impl Health {
+    /// Restores up to `amount`, never past `max`.
+    pub fn heal(&mut self, amount: i32, max: &MaxHealth) {
+        self.0 = (self.0 + amount).min(max.0).max(self.0);
+    }
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct MaxHealth(pub i32);
+
+#[derive(Component, Clone, Copy, Default)]
+pub struct Armor(pub f32);
+
+/// Fraction of every incoming hit that is ignored.
+#[derive(Component, Clone, Copy, Default)]
+pub struct DamageReduction(pub f32);
+
+/// Absorbs damage before health; recharges once no hit landed for a while.
+#[derive(Component, Clone, Copy, Default)]
+pub struct Shield {
+    pub current: f32,
+    pub max: f32,
+    pub since_hit: f32,
+}
+
+impl Shield {
+    /// Soaks up as much of the hit as it can and returns what gets through.
+    pub fn absorb(&mut self, amount: i32) -> i32 {
+        self.since_hit = 0.;
+        let absorbed = (amount as f32).min(self.current.floor());
+        self.current -= absorbed;
+        amount - absorbed as i32
+    }
+}
+
+/// Health regenerated per second; `progress` keeps the fraction until it adds up to a point.
+#[derive(Component, Clone, Copy, Default)]
+pub struct Regen {
+    pub rate: f32,
+    pub progress: f32,
+}
+
+#[derive(Component, Clone, Copy, Default)]
+pub struct Luck(pub f32);
+
+#[derive(Component, Clone, Copy, Default)]
+pub struct CooldownReduction(pub f32);
+
 #[derive(Component, Clone)]
 pub struct RemoveOnReset;
 
 #[derive(Component, Clone)]
 pub struct PointWorth(pub u32);
 
+/// XP carried by the crumb an enemy drops on death.
+#[derive(Component, Clone)]
+pub struct ExpDrop(pub u32);
+
 #[derive(Component)]
 pub struct Dead;
 
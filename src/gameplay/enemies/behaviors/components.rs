@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use rand::{thread_rng, Rng};
+
+/// Rushing in a straight line; regular movement is suspended meanwhile.
+#[derive(Component)]
+pub struct Charging {
+    pub direction: Vec2,
+    pub speed: f32,
+    pub remaining: f32,
+}
+
+/// Stands still, e.g. while winding up a charge or aiming.
+#[derive(Component)]
+pub struct Holding;
+
+/// Running away from the player; inserted by [`Flee`] when health gets low.
+#[derive(Component)]
+pub struct Fleeing;
+
+/// Approaches until `range`, then circles the player instead of closing in.
+#[derive(Component, Clone)]
+pub struct KeepDistance {
+    pub range: f32,
+    pub strafe: f32,
+}
+
+impl KeepDistance {
+    pub fn new(range: f32) -> Self {
+        let strafe = if thread_rng().gen_bool(0.5) { 1. } else { -1. };
+        Self { range, strafe }
+    }
+}
+
+/// Turns tail once health drops below `below` of the max.
+#[derive(Component, Clone)]
+pub struct Flee {
+    pub below: f32,
+}
+
+#[derive(Clone)]
+pub enum ChargeState {
+    Idle,
+    WindingUp(Timer),
+    Charging,
+    Recovering(Timer),
+}
+
+/// Stops, swells up for `windup` seconds, then dashes at the player.
+#[derive(Component, Clone)]
+pub struct ChargeAttack {
+    pub state: ChargeState,
+    pub trigger_range: f32,
+    pub windup: f32,
+    pub cooldown: f32,
+    pub speed: f32,
+    pub duration: f32,
+}
+
+#[derive(Clone)]
+pub enum SniperState {
+    Repositioning,
+    Aiming(Timer),
+    Cooldown(Timer),
+}
+
+/// Stops to aim with a visible line, then fires a single fast shot.
+#[derive(Component, Clone)]
+pub struct Sniper {
+    pub state: SniperState,
+    pub range: f32,
+    pub aim_time: f32,
+    pub cooldown: f32,
+    pub damage: i32,
+    pub shot_speed: f32,
+}
+
+/// The line a [`Sniper`] shows while aiming.
+#[derive(Component)]
+pub struct AimLine;
+
+/// On-death hook: breaks into `pieces` smaller, faster copies. Pieces split
+/// again while `generations` is above 1.
+#[derive(Component, Clone)]
+pub struct SplitOnDeath {
+    pub pieces: u32,
+    pub generations: u32,
+}
+
+/// Calls in `per_wave` minions every `timer` tick, keeping at most `cap` alive.
+#[derive(Component, Clone)]
+pub struct Summoner {
+    pub timer: Timer,
+    pub per_wave: u32,
+    pub cap: usize,
+}
+
+/// A minion, pointing back at the [`Summoner`] that called it in.
+#[derive(Component)]
+pub struct SummonedBy(pub Entity);
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{
+    pickups::systems::enemies_unfrozen, states::GameState, system_sets::DeathHooks,
+};
+
+use self::systems::{
+    charge_attacks, flee, interrupt_windups, keep_distance, move_charging, snipers_aim,
+    spawn_aim_lines, split_on_death, summon_minions, update_aim_lines, update_fleeing,
+};
+
+pub mod components;
+pub mod systems;
+
+pub struct BehaviorsPlugin;
+
+impl Plugin for BehaviorsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_aim_lines,
+                update_fleeing,
+                interrupt_windups,
+                (
+                    flee,
+                    keep_distance,
+                    charge_attacks,
+                    move_charging,
+                    snipers_aim,
+                    update_aim_lines,
+                    summon_minions,
+                )
+                    .chain()
+                    .run_if(enemies_unfrozen),
+            )
+                .chain()
+                .run_if(in_state(GameState::Running)),
+        )
+        .add_systems(Update, (split_on_death).in_set(DeathHooks));
+    }
+}
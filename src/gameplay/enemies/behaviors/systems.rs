@@ -0,0 +1,417 @@
+use bevy::{math::bounding::Aabb2d, prelude::*, utils::HashMap};
+use rand::{thread_rng, Rng};
+
+use crate::{
+    gameplay::{
+        arena::{
+            components::Solid,
+            systems::{line_of_sight, solid_box},
+        },
+        components::{
+            Critical, Damage, Dead, ExpDrop, Health, HitBlinkTimer, MaxHealth, PointWorth, Shooter,
+            ShotSpeed, Speed,
+        },
+        enemies::{bundles::EnemyBundle, components::Stunned, systems::turn_towards},
+        player::components::Player,
+    },
+    ShootEvent,
+};
+
+use super::components::{
+    AimLine, ChargeAttack, ChargeState, Charging, Flee, Fleeing, Holding, KeepDistance, Sniper,
+    SniperState, SplitOnDeath, SummonedBy, Summoner,
+};
+
+/// How far inside or outside its preferred range a strafer may drift before correcting.
+const RANGE_TOLERANCE: f32 = 40.;
+const WINDUP_SWELL: f32 = 0.3;
+const AIM_LINE_WIDTH: f32 = 2.;
+/// Size and speed of a split piece relative to the enemy it came from.
+const PIECE_SIZE: f32 = 0.6;
+const PIECE_SPEED: f32 = 1.4;
+const SUMMON_RADIUS: f32 = 50.;
+
+pub fn update_fleeing(
+    q_flee: Query<(&Flee, &Health, &MaxHealth, Has<Fleeing>, Entity)>,
+    mut commands: Commands,
+) {
+    q_flee
+        .iter()
+        .for_each(|(flee, health, max_health, fleeing, entity)| {
+            let low = (health.0 as f32) < flee.below * max_health.0 as f32;
+            if low && !fleeing {
+                commands.entity(entity).insert(Fleeing).remove::<Holding>();
+            } else if !low && fleeing {
+                commands.entity(entity).remove::<Fleeing>();
+            }
+        });
+}
+
+pub fn flee(
+    mut q_fleeing: Query<(&mut Transform, &Speed), (With<Fleeing>, Without<Charging>)>,
+    q_player: Query<&Transform, (With<Player>, Without<Fleeing>)>,
+    time: Res<Time>,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    q_fleeing.iter_mut().for_each(|(mut enemy_tr, speed)| {
+        let away = (enemy_tr.translation.xy() - player_tr.translation.xy()).normalize_or_zero();
+        enemy_tr.translation += (away * speed.0 * time.delta_seconds()).extend(0.);
+    });
+}
+
+pub fn keep_distance(
+    mut q_strafers: Query<
+        (&mut Transform, &Speed, &KeepDistance),
+        (
+            Without<Fleeing>,
+            Without<Holding>,
+            Without<Charging>,
+            Without<Stunned>,
+        ),
+    >,
+    q_player: Query<&Transform, (With<Player>, Without<KeepDistance>)>,
+    time: Res<Time>,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    let target = player_tr.translation.xy();
+    q_strafers
+        .iter_mut()
+        .for_each(|(mut enemy_tr, speed, keep_distance)| {
+            let offset = target - enemy_tr.translation.xy();
+            let distance = offset.length();
+            let towards = offset.normalize_or_zero();
+            let direction = if distance > keep_distance.range + RANGE_TOLERANCE {
+                towards
+            } else if distance < keep_distance.range - RANGE_TOLERANCE {
+                -towards
+            } else {
+                towards.perp() * keep_distance.strafe
+            };
+            enemy_tr.translation += (direction * speed.0 * time.delta_seconds()).extend(0.);
+            turn_towards(&mut enemy_tr, target, time.delta_seconds());
+        });
+}
+
+pub fn charge_attacks(
+    mut q_chargers: Query<
+        (&mut ChargeAttack, &mut Transform, Has<Charging>, Entity),
+        (Without<Fleeing>, Without<Stunned>),
+    >,
+    q_player: Query<&Transform, (With<Player>, Without<ChargeAttack>)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    let target = player_tr.translation.xy();
+    q_chargers
+        .iter_mut()
+        .for_each(|(mut charge, mut enemy_tr, charging, entity)| {
+            let offset = target - enemy_tr.translation.xy();
+            let ChargeAttack {
+                state,
+                trigger_range,
+                windup,
+                cooldown,
+                speed,
+                duration,
+            } = &mut *charge;
+            let next = match state {
+                ChargeState::Idle if offset.length() <= *trigger_range => {
+                    commands.entity(entity).insert(Holding);
+                    Some(ChargeState::WindingUp(Timer::from_seconds(
+                        *windup,
+                        TimerMode::Once,
+                    )))
+                }
+                ChargeState::Idle => None,
+                ChargeState::WindingUp(timer) => {
+                    timer.tick(time.delta());
+                    enemy_tr.scale = Vec3::splat(1. + WINDUP_SWELL * timer.fraction());
+                    timer.finished().then(|| {
+                        enemy_tr.scale = Vec3::ONE;
+                        commands
+                            .entity(entity)
+                            .remove::<Holding>()
+                            .insert(Charging {
+                                direction: offset.normalize_or_zero(),
+                                speed: *speed,
+                                remaining: *duration,
+                            });
+                        ChargeState::Charging
+                    })
+                }
+                // The charge runs until `move_charging` drops the component
+                ChargeState::Charging => (!charging).then(|| {
+                    ChargeState::Recovering(Timer::from_seconds(*cooldown, TimerMode::Once))
+                }),
+                ChargeState::Recovering(timer) => timer
+                    .tick(time.delta())
+                    .finished()
+                    .then_some(ChargeState::Idle),
+            };
+            if let Some(next) = next {
+                *state = next;
+            }
+        });
+}
+
+/// A stun throws away a charge wind-up or a sniper's aim, and they start over.
+pub fn interrupt_windups(
+    mut q_stunned: Query<
+        (
+            Option<&mut ChargeAttack>,
+            Option<&mut Sniper>,
+            &mut Transform,
+            Entity,
+        ),
+        Added<Stunned>,
+    >,
+    mut commands: Commands,
+) {
+    q_stunned
+        .iter_mut()
+        .for_each(|(charge, sniper, mut enemy_tr, entity)| {
+            if let Some(mut charge) = charge {
+                if let ChargeState::WindingUp(_) = charge.state {
+                    enemy_tr.scale = Vec3::ONE;
+                    commands.entity(entity).remove::<Holding>();
+                    charge.state = ChargeState::Recovering(Timer::from_seconds(
+                        charge.cooldown,
+                        TimerMode::Once,
+                    ));
+                }
+            }
+            if let Some(mut sniper) = sniper {
+                if let SniperState::Aiming(_) = sniper.state {
+                    commands.entity(entity).remove::<Holding>();
+                    sniper.state = SniperState::Repositioning;
+                }
+            }
+        });
+}
+
+pub fn move_charging(
+    mut q_charging: Query<(&mut Transform, &mut Charging, Entity)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    q_charging
+        .iter_mut()
+        .for_each(|(mut enemy_tr, mut charging, entity)| {
+            enemy_tr.translation +=
+                (charging.direction * charging.speed * time.delta_seconds()).extend(0.);
+            charging.remaining -= time.delta_seconds();
+            if charging.remaining <= 0. {
+                commands.entity(entity).remove::<Charging>();
+            }
+        });
+}
+
+pub fn spawn_aim_lines(q_snipers: Query<Entity, Added<Sniper>>, mut commands: Commands) {
+    q_snipers.iter().for_each(|sniper| {
+        commands.entity(sniper).with_children(|parent| {
+            parent.spawn((
+                AimLine,
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::RED.with_a(0.6),
+                        custom_size: Some(Vec2::new(0., AIM_LINE_WIDTH)),
+                        ..default()
+                    },
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+            ));
+        });
+    });
+}
+
+pub fn snipers_aim(
+    mut q_snipers: Query<
+        (&mut Sniper, &Transform, &Health, Entity),
+        (Without<Fleeing>, Without<Stunned>),
+    >,
+    q_player: Query<&Transform, With<Player>>,
+    q_solids: Query<(&Transform, &Sprite), With<Solid>>,
+    mut ev_shoot: EventWriter<ShootEvent>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    let target = player_tr.translation.xy();
+    let solids: Vec<Aabb2d> = q_solids
+        .iter()
+        .map(|(transform, sprite)| solid_box(transform, sprite))
+        .collect();
+    q_snipers
+        .iter_mut()
+        .for_each(|(mut sniper, enemy_tr, health, entity)| {
+            let source = enemy_tr.translation.xy();
+            let Sniper {
+                state,
+                range,
+                aim_time,
+                cooldown,
+                damage,
+                shot_speed,
+            } = &mut *sniper;
+            let next = match state {
+                SniperState::Repositioning
+                    if source.distance(target) <= *range + RANGE_TOLERANCE
+                        && line_of_sight(source, target, &solids) =>
+                {
+                    commands.entity(entity).insert(Holding);
+                    Some(SniperState::Aiming(Timer::from_seconds(
+                        *aim_time,
+                        TimerMode::Once,
+                    )))
+                }
+                SniperState::Repositioning => None,
+                SniperState::Aiming(timer) => timer.tick(time.delta()).finished().then(|| {
+                    commands.entity(entity).remove::<Holding>();
+                    // Killed while aiming, `dead_mark` just hasn't caught up yet
+                    if health.0 <= 0 {
+                        return SniperState::Repositioning;
+                    }
+                    ev_shoot.send(ShootEvent {
+                        source,
+                        target,
+                        damage: Damage(*damage),
+                        shooter: Shooter::Enemy,
+                        bullet_speed: ShotSpeed(*shot_speed),
+                        critical: Critical(false),
+                    });
+                    SniperState::Cooldown(Timer::from_seconds(*cooldown, TimerMode::Once))
+                }),
+                SniperState::Cooldown(timer) => timer
+                    .tick(time.delta())
+                    .finished()
+                    .then_some(SniperState::Repositioning),
+            };
+            if let Some(next) = next {
+                *state = next;
+            }
+        });
+}
+
+pub fn update_aim_lines(
+    q_snipers: Query<(&Sniper, &Transform, &Children)>,
+    mut q_lines: Query<
+        (&mut Transform, &mut Sprite, &mut Visibility),
+        (With<AimLine>, Without<Sniper>),
+    >,
+    q_player: Query<&Transform, (With<Player>, Without<AimLine>, Without<Sniper>)>,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    q_snipers.iter().for_each(|(sniper, sniper_tr, children)| {
+        let aiming = matches!(sniper.state, SniperState::Aiming(_));
+        let offset = player_tr.translation.xy() - sniper_tr.translation.xy();
+        let to_local = sniper_tr.rotation.inverse();
+        children.iter().for_each(|child| {
+            if let Ok((mut line_tr, mut sprite, mut visibility)) = q_lines.get_mut(*child) {
+                *visibility = if aiming {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+                if aiming {
+                    line_tr.translation = to_local * (offset / 2.).extend(-0.1);
+                    line_tr.rotation = to_local * Quat::from_rotation_z(offset.y.atan2(offset.x));
+                    sprite.custom_size = Some(Vec2::new(offset.length(), AIM_LINE_WIDTH));
+                }
+            }
+        });
+    });
+}
+
+/// Replaces a dying splitter with smaller copies of itself around where it fell.
+pub fn split_on_death(
+    q_dead: Query<
+        (
+            &SplitOnDeath,
+            &Transform,
+            &Sprite,
+            Option<&HitBlinkTimer>,
+            &Speed,
+            &MaxHealth,
+            &PointWorth,
+            &ExpDrop,
+        ),
+        With<Dead>,
+    >,
+    mut commands: Commands,
+) {
+    let mut rng = thread_rng();
+    q_dead.iter().for_each(
+        |(split, enemy_tr, sprite, blink, speed, max_health, point_worth, exp_drop)| {
+            // A killing blow leaves the sprite mid hit-flash
+            let color = blink.map_or(sprite.color, |blink| blink.return_to);
+            let size = sprite.custom_size.unwrap().x * PIECE_SIZE;
+            let offset = rng.gen_range(0. ..std::f32::consts::TAU);
+            (0..split.pieces).for_each(|i| {
+                let angle = offset + i as f32 * std::f32::consts::TAU / split.pieces as f32;
+                let position = enemy_tr.translation + (Vec2::from_angle(angle) * size).extend(0.);
+                let mut piece = commands.spawn(
+                    EnemyBundle::spawned(
+                        color,
+                        size,
+                        speed.0 * PIECE_SPEED,
+                        (max_health.0 / 2).max(1),
+                        point_worth.0 / 2,
+                        exp_drop.0 / 2,
+                    )
+                    .with_transform(Transform::from_translation(position)),
+                );
+                if split.generations > 1 {
+                    piece.insert(SplitOnDeath {
+                        pieces: split.pieces,
+                        generations: split.generations - 1,
+                    });
+                }
+            });
+        },
+    );
+}
+
+pub fn summon_minions(
+    mut q_summoners: Query<(&mut Summoner, &Transform, Entity), Without<Fleeing>>,
+    q_minions: Query<&SummonedBy>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let mut alive: HashMap<Entity, usize> = HashMap::new();
+    q_minions.iter().for_each(|SummonedBy(summoner)| {
+        *alive.entry(*summoner).or_default() += 1;
+    });
+    q_summoners
+        .iter_mut()
+        .for_each(|(mut summoner, summoner_tr, entity)| {
+            if !summoner.timer.tick(time.delta()).just_finished() {
+                return;
+            }
+            let room = summoner
+                .cap
+                .saturating_sub(alive.get(&entity).copied().unwrap_or(0));
+            let count = (summoner.per_wave as usize).min(room);
+            (0..count).for_each(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / count as f32;
+                let position =
+                    summoner_tr.translation + (Vec2::from_angle(angle) * SUMMON_RADIUS).extend(0.);
+                commands.spawn((
+                    EnemyBundle::spawned(Color::SALMON, 20., 140., 5, 2, 2)
+                        .with_transform(Transform::from_translation(position)),
+                    SummonedBy(entity),
+                ));
+            });
+        });
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BossPattern {
+    Charge,
+    BulletRing,
+    SummonAdds,
+}
+
+impl BossPattern {
+    pub fn cooldown(&self) -> f32 {
+        match self {
+            BossPattern::Charge => 3.,
+            BossPattern::BulletRing => 2.,
+            BossPattern::SummonAdds => 5.,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BossKind {
+    Juggernaut,
+    Broodmother,
+}
+
+impl BossKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossKind::Juggernaut => "Juggernaut",
+            BossKind::Broodmother => "Broodmother",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            BossKind::Juggernaut => Color::CRIMSON,
+            BossKind::Broodmother => Color::DARK_GREEN,
+        }
+    }
+
+    pub fn health(&self) -> i32 {
+        match self {
+            BossKind::Juggernaut => 600,
+            BossKind::Broodmother => 450,
+        }
+    }
+
+    /// Patterns in the order they take over as the boss loses health;
+    /// each phase covers an equal share of the health bar.
+    pub fn phases(&self) -> &'static [BossPattern] {
+        match self {
+            BossKind::Juggernaut => &[
+                BossPattern::Charge,
+                BossPattern::BulletRing,
+                BossPattern::SummonAdds,
+            ],
+            BossKind::Broodmother => &[
+                BossPattern::SummonAdds,
+                BossPattern::BulletRing,
+                BossPattern::Charge,
+            ],
+        }
+    }
+
+    /// Phase the boss should be in at this share of its max health.
+    pub fn phase_at(&self, fraction: f32) -> usize {
+        let phases = self.phases().len();
+        (((1. - fraction.clamp(0., 1.)) * phases as f32) as usize).min(phases - 1)
+    }
+}
+
+#[derive(Component)]
+pub struct Boss {
+    pub kind: BossKind,
+    pub phase: usize,
+    pub attack: Timer,
+}
+
+impl Boss {
+    pub fn new(kind: BossKind) -> Self {
+        Self {
+            kind,
+            phase: 0,
+            attack: Timer::from_seconds(kind.phases()[0].cooldown(), TimerMode::Repeating),
+        }
+    }
+
+    pub fn pattern(&self) -> BossPattern {
+        self.kind.phases()[self.phase]
+    }
+}
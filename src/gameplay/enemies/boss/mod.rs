@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{pickups::systems::enemies_unfrozen, states::GameState, system_sets::RunSet};
+
+use self::{
+    resources::BossSchedule,
+    systems::{
+        boss_attacks, drop_boss_chest, reset_boss_schedule, spawn_bosses, unleash_boss_attacks,
+        update_boss_phases,
+    },
+};
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossSchedule>()
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (reset_boss_schedule).in_set(RunSet::Reset),
+            )
+            .add_systems(
+                Update,
+                (
+                    spawn_bosses,
+                    drop_boss_chest,
+                    (update_boss_phases, boss_attacks, unleash_boss_attacks)
+                        .chain()
+                        .run_if(enemies_unfrozen),
+                )
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
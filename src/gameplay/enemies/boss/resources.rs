@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Time and score milestones that bring in the next boss.
+#[derive(Resource)]
+pub struct BossSchedule {
+    pub timer: Timer,
+    pub next_score: u32,
+    pub spawned: u32,
+}
+
+impl Default for BossSchedule {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(150., TimerMode::Repeating),
+            next_score: 1000,
+            spawned: 0,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use rand::{thread_rng, Rng};
+
+use crate::{
+    gameplay::{
+        arena::resources::ArenaConfig,
+        components::{Critical, Damage, Health, MainCamera, MaxHealth, Shooter, ShotSpeed},
+        enemies::{
+            behaviors::components::Charging,
+            bundles::{BossBundle, EnemyBundle},
+            components::{Enemy, Stunned, TelegraphIndicator, Telegraphing, WindUp},
+            systems::{advance_telegraph, begin_telegraph, clear_telegraph, offscreen_position},
+        },
+        pickups::{bundles::PickupBundle, components::PickupKind},
+        player::components::Player,
+    },
+    ui::score::resources::PlayerScore,
+    ShootEvent,
+};
+
+use super::{
+    components::{Boss, BossKind, BossPattern},
+    resources::BossSchedule,
+};
+
+const BOSS_SCORE_STEP: u32 = 2000;
+const CHARGE_SPEED: f32 = 600.;
+const CHARGE_DURATION: f32 = 0.6;
+const RING_BULLETS: usize = 16;
+const RING_DAMAGE: i32 = 5;
+const RING_SHOT_SPEED: f32 = 250.;
+const SUMMONED_ADDS: usize = 4;
+
+pub fn spawn_bosses(
+    mut schedule: ResMut<BossSchedule>,
+    score: Res<PlayerScore>,
+    q_boss: Query<(), With<Boss>>,
+    q_windows: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<&Transform, With<MainCamera>>,
+    arena: Res<ArenaConfig>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let time_milestone = schedule.timer.tick(time.delta()).just_finished();
+    let score_milestone = score.0 >= schedule.next_score;
+    if !(time_milestone || score_milestone) || !q_boss.is_empty() {
+        return;
+    }
+    let (Ok(window), Ok(camera_tr)) = (q_windows.get_single(), q_camera.get_single()) else {
+        return;
+    };
+    while schedule.next_score <= score.0 {
+        schedule.next_score += BOSS_SCORE_STEP;
+    }
+    let kind = match schedule.spawned % 2 {
+        0 => BossKind::Juggernaut,
+        _ => BossKind::Broodmother,
+    };
+    schedule.spawned += 1;
+    let position = offscreen_position(camera_tr, window, &arena, &mut thread_rng());
+    commands.spawn(BossBundle::new(kind, position));
+}
+
+pub fn reset_boss_schedule(mut schedule: ResMut<BossSchedule>) {
+    *schedule = BossSchedule::default();
+}
+
+pub fn update_boss_phases(mut q_bosses: Query<(&mut Boss, &Health, &MaxHealth), Changed<Health>>) {
+    q_bosses
+        .iter_mut()
+        .for_each(|(mut boss, health, max_health)| {
+            let phase = boss.kind.phase_at(health.0 as f32 / max_health.0 as f32);
+            if phase != boss.phase {
+                boss.phase = phase;
+                let cooldown = boss.pattern().cooldown();
+                boss.attack = Timer::from_seconds(cooldown, TimerMode::Repeating);
+            }
+        });
+}
+
+/// Winds up the current pattern whenever the attack timer comes around.
+pub fn boss_attacks(
+    mut q_bosses: Query<
+        (&mut Boss, &WindUp, &Sprite, Entity),
+        (Without<Charging>, Without<Telegraphing>, Without<Stunned>),
+    >,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    q_bosses
+        .iter_mut()
+        .for_each(|(mut boss, wind_up, sprite, entity)| {
+            if boss.attack.tick(time.delta()).just_finished() {
+                begin_telegraph(&mut commands, entity, wind_up, sprite);
+            }
+        });
+}
+
+/// Unleashes the boss pattern once its wind-up completes.
+pub fn unleash_boss_attacks(
+    mut q_bosses: Query<
+        (
+            &Boss,
+            &Transform,
+            &mut Telegraphing,
+            &Health,
+            &Children,
+            Entity,
+        ),
+        (With<Enemy>, Without<Stunned>),
+    >,
+    q_player: Query<&Transform, (With<Player>, Without<Boss>)>,
+    mut q_indicators: Query<
+        (&mut Transform, &mut Sprite),
+        (With<TelegraphIndicator>, Without<Enemy>, Without<Player>),
+    >,
+    mut ev_shoot: EventWriter<ShootEvent>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    q_bosses
+        .iter_mut()
+        .for_each(|(boss, boss_tr, mut telegraph, health, children, entity)| {
+            if !advance_telegraph(&mut telegraph, children, &mut q_indicators, time.delta()) {
+                return;
+            }
+            clear_telegraph(&mut commands, entity, children, &q_indicators);
+            if health.0 <= 0 {
+                return;
+            }
+            let source = boss_tr.translation.xy();
+            match boss.pattern() {
+                BossPattern::Charge => {
+                    commands.entity(entity).insert(Charging {
+                        direction: (player_tr.translation.xy() - source).normalize_or_zero(),
+                        speed: CHARGE_SPEED,
+                        remaining: CHARGE_DURATION,
+                    });
+                }
+                BossPattern::BulletRing => {
+                    let offset = thread_rng().gen_range(0. ..std::f32::consts::TAU);
+                    (0..RING_BULLETS).for_each(|i| {
+                        let angle = offset + i as f32 * std::f32::consts::TAU / RING_BULLETS as f32;
+                        ev_shoot.send(ShootEvent {
+                            source,
+                            target: source + Vec2::from_angle(angle),
+                            damage: Damage(RING_DAMAGE),
+                            shooter: Shooter::Enemy,
+                            bullet_speed: ShotSpeed(RING_SHOT_SPEED),
+                            critical: Critical(false),
+                        });
+                    });
+                }
+                BossPattern::SummonAdds => {
+                    (0..SUMMONED_ADDS).for_each(|i| {
+                        let angle = i as f32 * std::f32::consts::TAU / SUMMONED_ADDS as f32;
+                        let position = source + Vec2::from_angle(angle) * 80.;
+                        commands.spawn(
+                            EnemyBundle::default()
+                                .with_transform(Transform::from_translation(position.extend(0.))),
+                        );
+                    });
+                }
+            }
+        });
+}
+
+pub fn drop_boss_chest(
+    q_dead: Query<(&Transform, &Health), (With<Boss>, Changed<Health>)>,
+    mut commands: Commands,
+) {
+    q_dead
+        .iter()
+        .filter(|(_, health)| health.0 <= 0)
+        .for_each(|(boss_tr, _)| {
+            commands.spawn(PickupBundle::new(PickupKind::Chest, *boss_tr));
+        });
+}
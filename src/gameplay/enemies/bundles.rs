@@ -5,12 +5,19 @@ use bevy::{prelude::*, time::Stopwatch};
 use crate::gameplay::{
     bundles::ShooterBundle,
     components::{
-        Damage, Health, PointWorth, ReloadStopwatch, ReloadTime, RemoveOnReset, Shooter, ShotSpeed,
-        Speed,
+        CritChance, Damage, ExpDrop, Health, MaxHealth, PointWorth, ReloadStopwatch, ReloadTime,
+        RemoveOnReset, Shooter, ShotSpeed, Speed,
     },
+    pickups::components::{DropTable, BASIC_DROPS, SHOOTER_DROPS},
 };
 
-use super::components::Enemy;
+use super::{
+    behaviors::components::{
+        ChargeAttack, ChargeState, Flee, KeepDistance, Sniper, SniperState, SplitOnDeath, Summoner,
+    },
+    boss::components::{Boss, BossKind},
+    components::{Enemy, WindUp},
+};
 
 #[derive(Bundle, Clone)]
 pub struct EnemyBundle {
@@ -18,12 +25,42 @@ pub struct EnemyBundle {
     marker: Enemy,
     shooter_marker: Shooter,
     hp: Health,
+    max_hp: MaxHealth,
     sprite: SpriteBundle,
     remove_on_reset: RemoveOnReset,
     point_worth: PointWorth,
+    exp_drop: ExpDrop,
+    drop_table: DropTable,
 }
 
 impl EnemyBundle {
+    /// A plain chaser with the given look and numbers, used for spawned pieces and minions.
+    pub fn spawned(
+        color: Color,
+        size: f32,
+        speed: f32,
+        health: i32,
+        point_worth: u32,
+        exp_drop: u32,
+    ) -> Self {
+        Self {
+            speed: Speed(speed),
+            hp: Health(health),
+            max_hp: MaxHealth(health),
+            point_worth: PointWorth(point_worth),
+            exp_drop: ExpDrop(exp_drop),
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                ..default()
+            },
+            ..default()
+        }
+    }
+
     pub fn with_transform(self, transform: Transform) -> Self {
         Self {
             sprite: SpriteBundle {
@@ -40,7 +77,10 @@ impl Default for EnemyBundle {
         Self {
             speed: Speed(100.),
             hp: Health(10),
+            max_hp: MaxHealth(10),
             point_worth: PointWorth(5),
+            exp_drop: ExpDrop(5),
+            drop_table: BASIC_DROPS,
             marker: Enemy,
             shooter_marker: Shooter::Enemy,
             remove_on_reset: RemoveOnReset,
@@ -56,17 +96,21 @@ impl Default for EnemyBundle {
     }
 }
 
+/// Keeps its distance while shooting and runs off when badly hurt.
 #[derive(Bundle)]
 pub struct ShooterEnemyBundle {
     enemy: EnemyBundle,
     shooter: ShooterBundle,
+    wind_up: WindUp,
+    keep_distance: KeepDistance,
+    flee: Flee,
 }
 
 impl ShooterEnemyBundle {
     pub fn with_transform(self, transform: Transform) -> Self {
         Self {
             enemy: self.enemy.with_transform(transform),
-            shooter: self.shooter,
+            ..self
         }
     }
 }
@@ -76,7 +120,10 @@ impl Default for ShooterEnemyBundle {
         Self {
             enemy: EnemyBundle {
                 hp: Health(20),
+                max_hp: MaxHealth(20),
                 point_worth: PointWorth(10),
+                exp_drop: ExpDrop(12),
+                drop_table: SHOOTER_DROPS,
                 sprite: SpriteBundle {
                     sprite: Sprite {
                         color: Color::PURPLE,
@@ -92,7 +139,204 @@ impl Default for ShooterEnemyBundle {
                 since_last_reload: ReloadStopwatch(Stopwatch::new()),
                 reload_time: ReloadTime(time::Duration::from_secs(2)),
                 shot_speed: ShotSpeed(300.),
+                crit_chance: CritChance(0.),
+            },
+            wind_up: WindUp(0.6),
+            keep_distance: KeepDistance::new(350.),
+            flee: Flee { below: 0.3 },
+        }
+    }
+}
+
+/// Chases the player and dashes at them once close enough.
+#[derive(Bundle)]
+pub struct ChargerEnemyBundle {
+    enemy: EnemyBundle,
+    charge: ChargeAttack,
+}
+
+impl ChargerEnemyBundle {
+    pub fn with_transform(self, transform: Transform) -> Self {
+        Self {
+            enemy: self.enemy.with_transform(transform),
+            ..self
+        }
+    }
+}
+
+impl Default for ChargerEnemyBundle {
+    fn default() -> Self {
+        Self {
+            enemy: EnemyBundle {
+                speed: Speed(80.),
+                hp: Health(25),
+                max_hp: MaxHealth(25),
+                point_worth: PointWorth(12),
+                exp_drop: ExpDrop(15),
+                drop_table: SHOOTER_DROPS,
+                sprite: SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::ORANGE_RED,
+                        custom_size: Some(Vec2::splat(36.)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            },
+            charge: ChargeAttack {
+                state: ChargeState::Idle,
+                trigger_range: 250.,
+                windup: 0.8,
+                cooldown: 2.5,
+                speed: 550.,
+                duration: 0.5,
+            },
+        }
+    }
+}
+
+/// Hangs back at long range and fires aimed, fast shots.
+#[derive(Bundle)]
+pub struct SniperEnemyBundle {
+    enemy: EnemyBundle,
+    keep_distance: KeepDistance,
+    sniper: Sniper,
+    flee: Flee,
+}
+
+impl SniperEnemyBundle {
+    pub fn with_transform(self, transform: Transform) -> Self {
+        Self {
+            enemy: self.enemy.with_transform(transform),
+            ..self
+        }
+    }
+}
+
+impl Default for SniperEnemyBundle {
+    fn default() -> Self {
+        Self {
+            enemy: EnemyBundle {
+                speed: Speed(90.),
+                hp: Health(15),
+                max_hp: MaxHealth(15),
+                point_worth: PointWorth(15),
+                exp_drop: ExpDrop(18),
+                drop_table: SHOOTER_DROPS,
+                sprite: SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::TEAL,
+                        custom_size: Some(Vec2::splat(28.)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            },
+            keep_distance: KeepDistance::new(550.),
+            sniper: Sniper {
+                state: SniperState::Repositioning,
+                range: 550.,
+                aim_time: 1.2,
+                cooldown: 3.,
+                damage: 8,
+                shot_speed: 900.,
+            },
+            flee: Flee { below: 0.3 },
+        }
+    }
+}
+
+/// Breaks into smaller, faster cubes when killed.
+#[derive(Bundle)]
+pub struct SplitterEnemyBundle {
+    enemy: EnemyBundle,
+    split: SplitOnDeath,
+}
+
+impl SplitterEnemyBundle {
+    pub fn with_transform(self, transform: Transform) -> Self {
+        Self {
+            enemy: self.enemy.with_transform(transform),
+            ..self
+        }
+    }
+}
+
+impl Default for SplitterEnemyBundle {
+    fn default() -> Self {
+        Self {
+            enemy: EnemyBundle::spawned(Color::YELLOW_GREEN, 44., 80., 30, 10, 10),
+            split: SplitOnDeath {
+                pieces: 3,
+                generations: 2,
+            },
+        }
+    }
+}
+
+/// Hangs back and keeps calling in minions.
+#[derive(Bundle)]
+pub struct SummonerEnemyBundle {
+    enemy: EnemyBundle,
+    keep_distance: KeepDistance,
+    summoner: Summoner,
+}
+
+impl SummonerEnemyBundle {
+    pub fn with_transform(self, transform: Transform) -> Self {
+        Self {
+            enemy: self.enemy.with_transform(transform),
+            ..self
+        }
+    }
+}
+
+impl Default for SummonerEnemyBundle {
+    fn default() -> Self {
+        Self {
+            enemy: EnemyBundle::spawned(Color::MAROON, 36., 70., 40, 20, 25),
+            keep_distance: KeepDistance::new(400.),
+            summoner: Summoner {
+                timer: Timer::from_seconds(4., TimerMode::Repeating),
+                per_wave: 2,
+                cap: 6,
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct BossBundle {
+    enemy: EnemyBundle,
+    boss: Boss,
+    wind_up: WindUp,
+}
+
+impl BossBundle {
+    pub fn new(kind: BossKind, transform: Transform) -> Self {
+        Self {
+            enemy: EnemyBundle {
+                speed: Speed(60.),
+                hp: Health(kind.health()),
+                max_hp: MaxHealth(kind.health()),
+                point_worth: PointWorth(250),
+                exp_drop: ExpDrop(150),
+                drop_table: SHOOTER_DROPS,
+                sprite: SpriteBundle {
+                    sprite: Sprite {
+                        color: kind.color(),
+                        custom_size: Some(Vec2::splat(96.)),
+                        ..default()
+                    },
+                    transform,
+                    ..default()
+                },
+                ..default()
             },
+            boss: Boss::new(kind),
+            wind_up: WindUp(0.8),
         }
     }
 }
@@ -1,4 +1,20 @@
-use bevy::prelude::Component;
+use bevy::prelude::*;
 
 #[derive(Component, Clone)]
 pub struct Enemy;
+
+/// Seconds a shooter telegraphs each shot before firing.
+#[derive(Component, Clone)]
+pub struct WindUp(pub f32);
+
+/// Winding up a shot; it fires at the player once the timer runs out.
+#[derive(Component)]
+pub struct Telegraphing(pub Timer);
+
+/// The flash that grows over an enemy while it telegraphs a shot.
+#[derive(Component)]
+pub struct TelegraphIndicator;
+
+/// Staggered by a critical hit: no moving or attacking, and any wind-up is lost.
+#[derive(Component)]
+pub struct Stunned(pub Timer);
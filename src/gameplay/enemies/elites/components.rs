@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EliteAffix {
+    Tough,
+    Swift,
+    Reflective,
+    Vampiric,
+    Explosive,
+}
+
+impl EliteAffix {
+    pub const ALL: [EliteAffix; 5] = [
+        EliteAffix::Tough,
+        EliteAffix::Swift,
+        EliteAffix::Reflective,
+        EliteAffix::Vampiric,
+        EliteAffix::Explosive,
+    ];
+
+    pub fn color(&self) -> Color {
+        match self {
+            EliteAffix::Tough => Color::GRAY,
+            EliteAffix::Swift => Color::YELLOW,
+            EliteAffix::Reflective => Color::SILVER,
+            EliteAffix::Vampiric => Color::LIME_GREEN,
+            EliteAffix::Explosive => Color::ORANGE,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Elite;
+
+/// The colored outline drawn behind an elite.
+#[derive(Component)]
+pub struct EliteAura;
+
+/// Bounces player bullets back at them instead of taking the hit, some of the time.
+#[derive(Component)]
+pub struct ReflectsBullets {
+    pub chance: f64,
+}
+
+/// Periodically heals every other enemy within `radius`.
+#[derive(Component)]
+pub struct HealsAllies {
+    pub timer: Timer,
+    pub radius: f32,
+    pub amount: i32,
+}
+
+/// On-death hook: damages the player if they stand within `radius`.
+#[derive(Component)]
+pub struct ExplodesOnDeath {
+    pub radius: f32,
+    pub damage: i32,
+}
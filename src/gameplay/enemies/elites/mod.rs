@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{
+    pickups::systems::enemies_unfrozen, states::GameState, system_sets::DeathHooks,
+};
+
+use self::systems::{explode_on_death, heal_allies, roll_elites};
+
+pub mod components;
+pub mod systems;
+
+pub struct ElitesPlugin;
+
+impl Plugin for ElitesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (roll_elites, (heal_allies).run_if(enemies_unfrozen))
+                .run_if(in_state(GameState::Running)),
+        )
+        .add_systems(Update, (explode_on_death).in_set(DeathHooks));
+    }
+}
@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, thread_rng, Rng};
+
+use crate::{
+    gameplay::{
+        components::{
+            Armor, Critical, DamageReduction, Dead, ExpDrop, Health, Invulnerable, MaxHealth,
+            PointWorth, Shield, Shooter, Speed,
+        },
+        enemies::{boss::components::Boss, components::Enemy},
+        player::components::Player,
+        resources::RunClock,
+        systems::apply_damage,
+        weapons::bundles::WeaponEffectBundle,
+    },
+    DamageEvent,
+};
+
+use super::components::{
+    Elite, EliteAffix, EliteAura, ExplodesOnDeath, HealsAllies, ReflectsBullets,
+};
+
+const ELITE_BASE_CHANCE: f64 = 0.02;
+const ELITE_CHANCE_PER_MINUTE: f64 = 0.03;
+const ELITE_MAX_CHANCE: f64 = 0.4;
+const MAX_AFFIXES: usize = 3;
+const AURA_PADDING: f32 = 12.;
+
+/// Chance for a fresh spawn to be an elite, and for each extra affix it rolls.
+fn elite_chance(run_seconds: f32) -> f64 {
+    (ELITE_BASE_CHANCE + ELITE_CHANCE_PER_MINUTE * run_seconds as f64 / 60.).min(ELITE_MAX_CHANCE)
+}
+
+pub fn roll_elites(
+    mut q_spawned: Query<
+        (
+            &mut Health,
+            &mut MaxHealth,
+            &mut Speed,
+            &mut PointWorth,
+            &mut ExpDrop,
+            &Sprite,
+            Entity,
+        ),
+        (Added<Enemy>, Without<Boss>),
+    >,
+    clock: Res<RunClock>,
+    mut commands: Commands,
+) {
+    let mut rng = thread_rng();
+    let chance = elite_chance(clock.0.elapsed_secs());
+    q_spawned.iter_mut().for_each(
+        |(mut health, mut max_health, mut speed, mut point_worth, mut exp_drop, sprite, entity)| {
+            if !rng.gen_bool(chance) {
+                return;
+            }
+            let mut count = 1;
+            while count < MAX_AFFIXES && rng.gen_bool(chance) {
+                count += 1;
+            }
+            let affixes: Vec<EliteAffix> = EliteAffix::ALL
+                .choose_multiple(&mut rng, count)
+                .copied()
+                .collect();
+
+            let mut elite = commands.entity(entity);
+            affixes.iter().for_each(|affix| match affix {
+                EliteAffix::Tough => {
+                    max_health.0 = (max_health.0 as f32 * 2.5) as i32;
+                    health.0 = max_health.0;
+                }
+                EliteAffix::Swift => speed.0 *= 1.5,
+                EliteAffix::Reflective => {
+                    elite.insert(ReflectsBullets { chance: 0.5 });
+                }
+                EliteAffix::Vampiric => {
+                    elite.insert(HealsAllies {
+                        timer: Timer::from_seconds(1., TimerMode::Repeating),
+                        radius: 180.,
+                        amount: 3,
+                    });
+                }
+                EliteAffix::Explosive => {
+                    elite.insert(ExplodesOnDeath {
+                        radius: 110.,
+                        damage: 10,
+                    });
+                }
+            });
+            let reward = 1 + affixes.len() as u32;
+            point_worth.0 *= reward;
+            exp_drop.0 *= reward;
+
+            let size = sprite.custom_size.unwrap() + AURA_PADDING;
+            let color = affixes[0].color().with_a(0.7);
+            elite.insert(Elite).with_children(|parent| {
+                parent.spawn((
+                    EliteAura,
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color,
+                            custom_size: Some(size),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(0., 0., -0.1),
+                        ..default()
+                    },
+                ));
+            });
+        },
+    );
+}
+
+pub fn heal_allies(
+    mut q_healers: Query<(&mut HealsAllies, &Transform, Entity)>,
+    mut q_enemies: Query<
+        (&Transform, &mut Health, &MaxHealth, Entity),
+        (With<Enemy>, Without<Dead>, Without<HealsAllies>),
+    >,
+    time: Res<Time>,
+) {
+    q_healers
+        .iter_mut()
+        .for_each(|(mut healer, healer_tr, healer_entity)| {
+            if !healer.timer.tick(time.delta()).just_finished() {
+                return;
+            }
+            let center = healer_tr.translation.xy();
+            q_enemies
+                .iter_mut()
+                .filter(|(enemy_tr, health, _, entity)| {
+                    *entity != healer_entity
+                        && health.0 > 0
+                        && enemy_tr.translation.xy().distance(center) <= healer.radius
+                })
+                .for_each(|(_, mut health, max_health, _)| {
+                    health.heal(healer.amount, max_health);
+                });
+        });
+}
+
+/// Bursts a dying explosive elite, hurting the player if they stand within its radius.
+pub fn explode_on_death(
+    q_dead: Query<(&ExplodesOnDeath, &Transform), With<Dead>>,
+    mut q_player: Query<
+        (
+            &Transform,
+            &mut Health,
+            Option<&Invulnerable>,
+            Option<&Armor>,
+            Option<&DamageReduction>,
+            Option<&mut Shield>,
+            Entity,
+        ),
+        (With<Player>, Without<ExplodesOnDeath>),
+    >,
+    mut ev_damage: EventWriter<DamageEvent>,
+    mut commands: Commands,
+) {
+    q_dead.iter().for_each(|(explosion, enemy_tr)| {
+        let center = enemy_tr.translation.xy();
+        commands.spawn(WeaponEffectBundle::colored(
+            EliteAffix::Explosive.color().with_a(0.6),
+            center,
+            Vec2::ZERO,
+            Vec2::splat(explosion.radius * 2.),
+        ));
+        let Ok((player_tr, mut health, invulnerable, armor, reduction, mut shield, player)) =
+            q_player.get_single_mut()
+        else {
+            return;
+        };
+        let position = player_tr.translation.xy();
+        if invulnerable.is_some() || position.distance(center) > explosion.radius {
+            return;
+        }
+        apply_damage(
+            DamageEvent {
+                target: player,
+                position,
+                amount: explosion.damage,
+                dealt_by: Shooter::Enemy,
+                critical: Critical(false),
+            },
+            &mut health,
+            armor,
+            reduction,
+            shield.as_deref_mut(),
+            &mut ev_damage,
+        );
+    });
+}
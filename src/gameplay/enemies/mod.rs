@@ -1,14 +1,23 @@
 use bevy::prelude::*;
 
-use crate::gameplay::states::GameState;
+use crate::gameplay::{pickups::systems::enemies_unfrozen, states::GameState, system_sets::RunSet};
 
 use self::{
+    behaviors::BehaviorsPlugin,
+    boss::BossPlugin,
+    elites::ElitesPlugin,
     resources::EnemySpawnTimer,
-    systems::{enemies_shoot, enemy_spawner, get_enemy_collisions, move_enemies},
+    systems::{
+        enemies_shoot, enemy_spawner, fire_telegraphed_shots, get_enemy_collisions,
+        interrupt_telegraphs, move_enemies, reset_spawn_timer, stun_on_crit, tick_stuns,
+    },
 };
 
+pub mod behaviors;
+pub mod boss;
 pub mod bundles;
 pub mod components;
+pub mod elites;
 pub mod resources;
 pub mod systems;
 
@@ -16,13 +25,24 @@ pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(EnemySpawnTimer(Timer::from_seconds(0.5, TimerMode::Once)))
+        app.add_plugins((BehaviorsPlugin, BossPlugin, ElitesPlugin))
+            .insert_resource(EnemySpawnTimer::default())
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (reset_spawn_timer).in_set(RunSet::Reset),
+            )
             .add_systems(
                 Update,
                 (
                     enemy_spawner,
-                    move_enemies,
-                    enemies_shoot,
+                    (stun_on_crit, interrupt_telegraphs).chain(),
+                    (
+                        tick_stuns,
+                        move_enemies,
+                        enemies_shoot,
+                        fire_telegraphed_shots,
+                    )
+                        .run_if(enemies_unfrozen),
                     get_enemy_collisions,
                 )
                     .run_if(in_state(GameState::Running)),
@@ -2,3 +2,9 @@ use bevy::prelude::*;
 
 #[derive(Resource)]
 pub struct EnemySpawnTimer(pub Timer);
+
+impl Default for EnemySpawnTimer {
+    fn default() -> Self {
+        EnemySpawnTimer(Timer::from_seconds(0.5, TimerMode::Once))
+    }
+}
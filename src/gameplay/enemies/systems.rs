@@ -1,14 +1,21 @@
 use crate::{
     gameplay::{
+        arena::{
+            components::Solid,
+            resources::ArenaConfig,
+            systems::{line_of_sight, solid_box},
+        },
         components::{
-            Damage, Distance, Health, Invulnerable, MainCamera, MyDirection, Pushed,
-            ReloadStopwatch, ReloadTime, Shooter, ShotSpeed, Speed,
+            Armor, CritChance, Critical, Damage, DamageReduction, Dead, Distance, Health,
+            Invulnerable, MainCamera, MyDirection, Pushed, ReloadStopwatch, ReloadTime, Shield,
+            Shooter, ShotSpeed, Speed,
         },
         get_direction,
         player::components::Player,
+        systems::{apply_damage, roll_crit},
         EnemyVariant,
     },
-    ShootEvent,
+    DamageEvent, ShootEvent,
 };
 use bevy::{
     math::bounding::{Aabb2d, IntersectsVolume},
@@ -17,7 +24,37 @@ use bevy::{
 };
 use rand::Rng;
 
-use super::{components::Enemy, resources::EnemySpawnTimer};
+use super::{
+    behaviors::components::{Charging, Fleeing, Holding, KeepDistance},
+    boss::components::Boss,
+    components::{Enemy, Stunned, TelegraphIndicator, Telegraphing, WindUp},
+    resources::EnemySpawnTimer,
+};
+
+/// Shooters hold their fire until the player is this close.
+const ENEMY_SHOT_RANGE: f32 = 700.;
+const STUN_TIME: f32 = 0.4;
+/// How far from the arena walls enemies may spawn.
+const SPAWN_MARGIN: f32 = 60.;
+
+/// A random point just outside the screen corners, so enemies walk in from off-screen.
+/// Near the arena edge the point is pulled back inside the walls.
+pub fn offscreen_position(
+    camera_tr: &Transform,
+    window: &Window,
+    arena: &ArenaConfig,
+    rng: &mut impl Rng,
+) -> Transform {
+    let income_angle = rng.gen_range(0. ..std::f32::consts::TAU);
+    let distance = ((window.width() / 2.).powf(2.) + (window.height() / 2.).powf(2.)).sqrt();
+    let mut position =
+        Transform::from_translation(camera_tr.translation + Vec3::new(distance, 0., 0.));
+    position.rotate_around(Vec3::ZERO, Quat::from_rotation_z(income_angle));
+    position.translation = arena
+        .clamp(position.translation.xy(), SPAWN_MARGIN)
+        .extend(position.translation.z);
+    position
+}
 
 pub fn enemy_spawner(
     mut commands: Commands,
@@ -25,21 +62,15 @@ pub fn enemy_spawner(
     mut timer: ResMut<EnemySpawnTimer>,
     q_windows: Query<&Window, With<PrimaryWindow>>,
     q_camera: Query<&Transform, With<MainCamera>>,
+    arena: Res<ArenaConfig>,
 ) {
     if timer.0.tick(time.delta()).just_finished() {
         if let Ok(camera_tr) = q_camera.get_single() {
             let mut rng = rand::thread_rng();
-            let new_delay: f32 = rng.gen_range((0.25)..(0.75));
+            let new_delay: f32 = rng.gen_range(0.25..0.75);
             timer.0 = Timer::from_seconds(new_delay, TimerMode::Once);
 
-            let income_angle = rng.gen_range((0.)..std::f32::consts::TAU);
-            let primary_window = q_windows.single();
-            let distance = ((primary_window.width() / 2.).powf(2.)
-                + (primary_window.height() / 2.).powf(2.))
-            .sqrt();
-            let mut position =
-                Transform::from_translation(camera_tr.translation + Vec3::new(distance, 0., 0.));
-            position.rotate_around(Vec3::ZERO, Quat::from_rotation_z(income_angle));
+            let position = offscreen_position(camera_tr, q_windows.single(), &arena, &mut rng);
             let new_enemy = rand::random::<EnemyVariant>();
 
             match new_enemy {
@@ -49,14 +80,59 @@ pub fn enemy_spawner(
                 EnemyVariant::Shooter(s) => {
                     commands.spawn(s.with_transform(position));
                 }
+                EnemyVariant::Charger(c) => {
+                    commands.spawn(c.with_transform(position));
+                }
+                EnemyVariant::Sniper(s) => {
+                    commands.spawn(s.with_transform(position));
+                }
+                EnemyVariant::Splitter(s) => {
+                    commands.spawn(s.with_transform(position));
+                }
+                EnemyVariant::Summoner(s) => {
+                    commands.spawn(s.with_transform(position));
+                }
             }
         }
     }
 }
 
+pub fn reset_spawn_timer(mut timer: ResMut<EnemySpawnTimer>) {
+    *timer = EnemySpawnTimer::default();
+}
+
+/// Rotates an enemy so its front (local +Y) turns toward `target` at a capped rate.
+pub fn turn_towards(enemy_tr: &mut Transform, target: Vec2, delta_seconds: f32) {
+    let enemy_fwd = (enemy_tr.rotation * Vec3::Y).xy();
+    let dir_player = (target - enemy_tr.translation.xy()).normalize();
+    let forward_dot_player = enemy_fwd.dot(dir_player);
+
+    // (forward_dot_player - 1.0).abs() < f32::EPSILON;
+
+    let enemy_right = (enemy_tr.rotation * Vec3::X).xy();
+    let right_dot = enemy_right.dot(dir_player);
+    let rot_sign = -f32::copysign(1.0, right_dot);
+    let max_angle = forward_dot_player.clamp(-1.0, 1.0).acos();
+
+    let rotation_angle = rot_sign * (10. * delta_seconds).min(max_angle);
+
+    enemy_tr.rotate_z(rotation_angle);
+}
+
+/// Plain chasing, for enemies without a movement behavior of their own.
 pub fn move_enemies(
     time: Res<Time>,
-    mut q_enemies: Query<(&mut Transform, &Speed, &Sprite), With<Enemy>>,
+    mut q_enemies: Query<
+        (&mut Transform, &Speed, &Sprite),
+        (
+            With<Enemy>,
+            Without<Charging>,
+            Without<Holding>,
+            Without<Fleeing>,
+            Without<KeepDistance>,
+            Without<Stunned>,
+        ),
+    >,
     q_player: Query<(&Transform, &Sprite), (With<Player>, Without<Enemy>)>,
 ) {
     if let Ok((player_tr, player_sprite)) = q_player.get_single() {
@@ -82,21 +158,11 @@ pub fn move_enemies(
                     enemy_tr.translation -= delta;
                 }
 
-                let enemy_fwd = (enemy_tr.rotation * Vec3::Y).xy();
-                let dir_player =
-                    (player_tr.translation.xy() - enemy_tr.translation.xy()).normalize();
-                let forward_dot_player = enemy_fwd.dot(dir_player);
-
-                // (forward_dot_player - 1.0).abs() < f32::EPSILON;
-
-                let enemy_right = (enemy_tr.rotation * Vec3::X).xy();
-                let right_dot = enemy_right.dot(dir_player);
-                let rot_sign = -f32::copysign(1.0, right_dot);
-                let max_angle = forward_dot_player.clamp(-1.0, 1.0).acos();
-
-                let rotation_angle = rot_sign * (10. * time.delta_seconds()).min(max_angle);
-
-                enemy_tr.rotate_z(rotation_angle);
+                turn_towards(
+                    &mut enemy_tr,
+                    player_tr.translation.xy(),
+                    time.delta_seconds(),
+                );
             });
     }
 }
@@ -111,22 +177,53 @@ pub fn enemies_shoot(
             &ReloadTime,
             &Damage,
             &ShotSpeed,
+            &CritChance,
+            Option<&WindUp>,
+            &Sprite,
+            Entity,
         ),
-        With<Enemy>,
+        (With<Enemy>, Without<Telegraphing>, Without<Stunned>),
     >,
+    q_solids: Query<(&Transform, &Sprite), With<Solid>>,
     mut ev_shoot: EventWriter<ShootEvent>,
+    mut commands: Commands,
 ) {
     if let Ok(player_tr) = q_player.get_single() {
+        let solids: Vec<Aabb2d> = q_solids
+            .iter()
+            .map(|(transform, sprite)| solid_box(transform, sprite))
+            .collect();
         q_enemies.iter_mut().for_each(
-            |(e_tr, mut e_reload, e_reload_time, e_damage, e_shot_speed)| {
-                if e_reload.0.tick(time.delta()).elapsed() >= e_reload_time.0 {
+            |(
+                e_tr,
+                mut e_reload,
+                e_reload_time,
+                e_damage,
+                e_shot_speed,
+                e_crit_chance,
+                e_wind_up,
+                e_sprite,
+                e_entity,
+            )| {
+                let in_range =
+                    e_tr.translation.xy().distance(player_tr.translation.xy()) <= ENEMY_SHOT_RANGE;
+                if e_reload.0.tick(time.delta()).elapsed() >= e_reload_time.0
+                    && in_range
+                    && line_of_sight(e_tr.translation.xy(), player_tr.translation.xy(), &solids)
+                {
                     e_reload.0.reset();
+                    if let Some(wind_up) = e_wind_up {
+                        begin_telegraph(&mut commands, e_entity, wind_up, e_sprite);
+                        return;
+                    }
+                    let (damage, critical) = roll_crit(e_damage, e_crit_chance);
                     ev_shoot.send(ShootEvent {
                         source: e_tr.translation.xy(),
                         target: player_tr.translation.xy(),
-                        damage: e_damage.clone(),
+                        damage,
                         shooter: Shooter::Enemy,
                         bullet_speed: e_shot_speed.clone(),
+                        critical,
                     });
                 }
             },
@@ -134,6 +231,168 @@ pub fn enemies_shoot(
     }
 }
 
+/// Starts a wind-up on `entity`, with a flash over its sprite that grows until it attacks.
+pub fn begin_telegraph(commands: &mut Commands, entity: Entity, wind_up: &WindUp, sprite: &Sprite) {
+    commands
+        .entity(entity)
+        .insert(Telegraphing(Timer::from_seconds(
+            wind_up.0,
+            TimerMode::Once,
+        )))
+        .with_children(|parent| {
+            parent.spawn((
+                TelegraphIndicator,
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::WHITE.with_a(0.),
+                        custom_size: sprite.custom_size,
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(0., 0., 0.1).with_scale(Vec3::ZERO),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Ticks a wind-up and grows its flash to match. Returns whether the wind-up is over.
+pub fn advance_telegraph(
+    telegraph: &mut Telegraphing,
+    children: &Children,
+    q_indicators: &mut Query<
+        (&mut Transform, &mut Sprite),
+        (With<TelegraphIndicator>, Without<Enemy>, Without<Player>),
+    >,
+    delta: std::time::Duration,
+) -> bool {
+    let fraction = telegraph.0.tick(delta).fraction();
+    children.iter().for_each(|child| {
+        if let Ok((mut indicator_tr, mut sprite)) = q_indicators.get_mut(*child) {
+            indicator_tr.scale = Vec3::splat(fraction);
+            sprite.color.set_a(0.8 * fraction);
+        }
+    });
+    telegraph.0.finished()
+}
+
+/// Grows the telegraph flash and fires once the wind-up completes.
+pub fn fire_telegraphed_shots(
+    time: Res<Time>,
+    q_player: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut q_enemies: Query<
+        (
+            &Transform,
+            &mut Telegraphing,
+            &Health,
+            &Damage,
+            &ShotSpeed,
+            &CritChance,
+            &Children,
+            Entity,
+        ),
+        (With<Enemy>, Without<Boss>, Without<Stunned>),
+    >,
+    mut q_indicators: Query<
+        (&mut Transform, &mut Sprite),
+        (With<TelegraphIndicator>, Without<Enemy>, Without<Player>),
+    >,
+    mut ev_shoot: EventWriter<ShootEvent>,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    q_enemies.iter_mut().for_each(
+        |(
+            e_tr,
+            mut telegraph,
+            e_health,
+            e_damage,
+            e_shot_speed,
+            e_crit_chance,
+            children,
+            entity,
+        )| {
+            if !advance_telegraph(&mut telegraph, children, &mut q_indicators, time.delta()) {
+                return;
+            }
+            clear_telegraph(&mut commands, entity, children, &q_indicators);
+            // Killed during the wind-up, `dead_mark` just hasn't caught up yet
+            if e_health.0 <= 0 {
+                return;
+            }
+            let (damage, critical) = roll_crit(e_damage, e_crit_chance);
+            ev_shoot.send(ShootEvent {
+                source: e_tr.translation.xy(),
+                target: player_tr.translation.xy(),
+                damage,
+                shooter: Shooter::Enemy,
+                bullet_speed: e_shot_speed.clone(),
+                critical,
+            });
+        },
+    );
+}
+
+pub fn interrupt_telegraphs(
+    q_stunned: Query<(&Children, Entity), (With<Telegraphing>, Added<Stunned>)>,
+    q_indicators: Query<
+        (&mut Transform, &mut Sprite),
+        (With<TelegraphIndicator>, Without<Enemy>, Without<Player>),
+    >,
+    mut commands: Commands,
+) {
+    q_stunned.iter().for_each(|(children, entity)| {
+        clear_telegraph(&mut commands, entity, children, &q_indicators);
+    });
+}
+
+pub fn clear_telegraph(
+    commands: &mut Commands,
+    entity: Entity,
+    children: &Children,
+    q_indicators: &Query<
+        (&mut Transform, &mut Sprite),
+        (With<TelegraphIndicator>, Without<Enemy>, Without<Player>),
+    >,
+) {
+    commands.entity(entity).remove::<Telegraphing>();
+    children
+        .iter()
+        .filter(|child| q_indicators.contains(**child))
+        .for_each(|child| commands.entity(*child).despawn());
+}
+
+pub fn stun_on_crit(
+    mut ev_damage: EventReader<DamageEvent>,
+    q_enemies: Query<(), (With<Enemy>, Without<Dead>)>,
+    mut commands: Commands,
+) {
+    ev_damage
+        .read()
+        .filter(|ev| {
+            matches!(ev.dealt_by, Shooter::Player) && ev.critical.0 && q_enemies.contains(ev.target)
+        })
+        .for_each(|ev| {
+            // The enemy may already be queued for despawn this frame
+            commands
+                .entity(ev.target)
+                .try_insert(Stunned(Timer::from_seconds(STUN_TIME, TimerMode::Once)));
+        });
+}
+
+pub fn tick_stuns(
+    mut q_stunned: Query<(&mut Stunned, Entity)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    q_stunned.iter_mut().for_each(|(mut stunned, entity)| {
+        if stunned.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Stunned>();
+        }
+    });
+}
+
 pub fn get_enemy_collisions(
     mut q_player: Query<
         (
@@ -141,15 +400,27 @@ pub fn get_enemy_collisions(
             &Sprite,
             &mut Health,
             Option<&Invulnerable>,
+            Option<&Armor>,
+            Option<&DamageReduction>,
+            Option<&mut Shield>,
             Entity,
         ),
         With<Player>,
     >,
     q_enemies: Query<(&Transform, &Sprite), (Without<Player>, With<Enemy>)>,
+    mut ev_damage: EventWriter<DamageEvent>,
     mut commands: Commands,
 ) {
-    if let Ok((player_tr, player_sprite, mut player_hp, player_invulnerable, player_entity)) =
-        q_player.get_single_mut()
+    if let Ok((
+        player_tr,
+        player_sprite,
+        mut player_hp,
+        player_invulnerable,
+        player_armor,
+        player_reduction,
+        mut player_shield,
+        player_entity,
+    )) = q_player.get_single_mut()
     {
         let player_box = Aabb2d::new(
             player_tr.translation.xy(),
@@ -169,7 +440,20 @@ pub fn get_enemy_collisions(
                 );
 
                 if player_invulnerable.is_none() {
-                    player_hp.0 -= 5;
+                    apply_damage(
+                        DamageEvent {
+                            target: player_entity,
+                            position: player_tr.translation.xy(),
+                            amount: 5,
+                            dealt_by: Shooter::Enemy,
+                            critical: Critical(false),
+                        },
+                        &mut player_hp,
+                        player_armor,
+                        player_reduction,
+                        player_shield.as_deref_mut(),
+                        &mut ev_damage,
+                    );
                 }
 
                 commands.entity(player_entity).insert(Pushed {
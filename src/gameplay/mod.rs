@@ -4,32 +4,52 @@ use rand::{
     Rng,
 };
 
-use crate::ShootEvent;
+use crate::{DamageEvent, NewRunEvent, ShootEvent};
 
 use self::{
+    arena::ArenaPlugin,
+    background::BackgroundPlugin,
     components::{MyDirection, Speed},
     enemies::{
-        bundles::{EnemyBundle, ShooterEnemyBundle},
+        bundles::{
+            ChargerEnemyBundle, EnemyBundle, ShooterEnemyBundle, SniperEnemyBundle,
+            SplitterEnemyBundle, SummonerEnemyBundle,
+        },
         EnemyPlugin,
     },
+    pickups::PickupsPlugin,
     player::PlayerPlugin,
     projectiles::ProjectilesPlugin,
+    resources::{RunClock, RunSeed, ScreenShake},
     states::GameState,
-    system_sets::{GameplaySet, InputSet},
+    stats::StatsPlugin,
+    system_sets::{DeathHooks, GameplaySet, InputSet, RunSet},
     systems::{
-        dead_cleanup, dead_mark, draw_camera, fix_camera_to_player, invulnerable_tick,
-        keyboard_input, mouse_input, on_hit_highlight, push_processor, stop_highlight,
+        add_screen_shake, begin_run, clear_events, dead_cleanup, dead_mark, despawn_run_entities,
+        draw_camera, fix_camera_to_player, invulnerable_tick, keyboard_input, mouse_input,
+        on_hit_highlight, push_processor, recharge_shields, regenerate_health, request_new_run,
+        reset_camera, reset_run_clock, reset_run_seed, reset_screen_shake, shake_camera,
+        stop_highlight, tick_run_clock,
     },
+    upgrades::UpgradesPlugin,
+    weapons::WeaponsPlugin,
 };
 
+pub mod arena;
+pub mod background;
 pub mod bundles;
 pub mod components;
 pub mod enemies;
+pub mod pickups;
 pub mod player;
 pub mod projectiles;
+pub mod resources;
 pub mod states;
+pub mod stats;
 pub mod system_sets;
 pub mod systems;
+pub mod upgrades;
+pub mod weapons;
 
 fn get_delta(direction: &MyDirection, speed: &Speed, time: &Res<Time>) -> Vec3 {
     Vec3::new(
@@ -42,13 +62,21 @@ fn get_delta(direction: &MyDirection, speed: &Speed, time: &Res<Time>) -> Vec3 {
 enum EnemyVariant {
     Basic(EnemyBundle),
     Shooter(ShooterEnemyBundle),
+    Charger(ChargerEnemyBundle),
+    Sniper(SniperEnemyBundle),
+    Splitter(SplitterEnemyBundle),
+    Summoner(SummonerEnemyBundle),
 }
 
 impl Distribution<EnemyVariant> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> EnemyVariant {
         match rng.gen_range(0..100) {
-            0..=95 => EnemyVariant::Basic(EnemyBundle::default()),
-            _ => EnemyVariant::Shooter(ShooterEnemyBundle::default()),
+            0..=85 => EnemyVariant::Basic(EnemyBundle::default()),
+            86..=89 => EnemyVariant::Charger(ChargerEnemyBundle::default()),
+            90..=92 => EnemyVariant::Splitter(SplitterEnemyBundle::default()),
+            93..=95 => EnemyVariant::Shooter(ShooterEnemyBundle::default()),
+            96..=97 => EnemyVariant::Summoner(SummonerEnemyBundle::default()),
+            _ => EnemyVariant::Sniper(SniperEnemyBundle::default()),
         }
     }
 }
@@ -75,7 +103,16 @@ pub struct GameplayPlugin;
 impl Plugin for GameplayPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<RunClock>()
+            .init_resource::<RunSeed>()
             .add_event::<ShootEvent>()
+            .add_event::<DamageEvent>()
+            .add_event::<NewRunEvent>()
+            .configure_sets(
+                OnEnter(GameState::Starting),
+                (RunSet::Cleanup, RunSet::Reset, RunSet::Setup).chain(),
+            )
             .configure_sets(
                 Update,
                 (
@@ -87,22 +124,572 @@ impl Plugin for GameplayPlugin {
                 )
                     .run_if(in_state(GameState::Running)),
             )
+            .configure_sets(
+                Update,
+                // Between the two so each hook sees every `Dead` enemy exactly once
+                DeathHooks
+                    .in_set(GameplaySet::Bullets)
+                    .after(dead_mark)
+                    .before(dead_cleanup),
+            )
             .add_systems(Startup, (draw_camera).chain())
+            .add_systems(OnEnter(GameState::MainMenu), despawn_run_entities)
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (
+                    (despawn_run_entities).in_set(RunSet::Cleanup),
+                    (
+                        reset_camera,
+                        reset_screen_shake,
+                        reset_run_clock,
+                        reset_run_seed,
+                        clear_events::<ShootEvent>,
+                        clear_events::<DamageEvent>,
+                    )
+                        .in_set(RunSet::Reset),
+                    (begin_run).in_set(RunSet::Setup),
+                ),
+            )
             .add_systems(
                 Update,
                 (
                     (mouse_input).in_set(InputSet::Mouse),
                     (keyboard_input).in_set(InputSet::Keyboard),
+                    // A new run overrides any state change gameplay queued this frame
+                    (request_new_run).after(GameplaySet::Player),
                     (stop_highlight, dead_mark, dead_cleanup).in_set(GameplaySet::Bullets),
                     (
                         push_processor,
                         on_hit_highlight,
                         invulnerable_tick,
-                        fix_camera_to_player,
+                        regenerate_health,
+                        recharge_shields,
+                        tick_run_clock,
+                        add_screen_shake,
+                        (fix_camera_to_player, shake_camera).chain(),
                     )
                         .in_set(GameplaySet::Global),
                 ),
             )
-            .add_plugins((PlayerPlugin, EnemyPlugin, ProjectilesPlugin));
+            .add_plugins((
+                ArenaPlugin,
+                BackgroundPlugin,
+                PlayerPlugin,
+                EnemyPlugin,
+                ProjectilesPlugin,
+                PickupsPlugin,
+                StatsPlugin,
+                UpgradesPlugin,
+                WeaponsPlugin,
+            ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        asset::AssetPlugin,
+        input::InputPlugin,
+        math::bounding::{Aabb2d, IntersectsVolume},
+        window::WindowPlugin,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        gameplay::{
+            arena::{resources::ArenaConfig, systems::generate_layout},
+            background::{components::Chunk, systems::chunk_of},
+            components::{Critical, Damage, Health, MainCamera, RemoveOnReset, Shooter, ShotSpeed},
+            enemies::{
+                behaviors::components::{Charging, SplitOnDeath},
+                boss::{
+                    components::{Boss, BossKind},
+                    resources::BossSchedule,
+                },
+                bundles::{BossBundle, EnemyBundle, ShooterEnemyBundle, SplitterEnemyBundle},
+                components::{Enemy, Stunned, TelegraphIndicator, Telegraphing},
+                resources::EnemySpawnTimer,
+            },
+            pickups::{
+                components::PickupKind,
+                events::PickupEvent,
+                resources::{EnemyFreeze, PendingChests, PlayerGold},
+            },
+            player::{
+                components::Player,
+                crumbs::events::LevelUpEvent,
+                resources::{PendingLevelUps, PlayerExperience},
+            },
+            upgrades::{
+                assets::{UpgradeId, UpgradeRegistry},
+                events::UpgradeStatEvent,
+                resources::{
+                    BanishedUpgrades, UpgradeCharges, UpgradeOffer, UpgradeRegistryHandle,
+                    UpgradeStacks,
+                },
+            },
+            weapons::components::{OrbitingBlade, Weapon, WeaponKind},
+        },
+        settings::resources::Settings,
+        ui::{score::resources::PlayerScore, UiPlugin},
+        DamageEvent, NewRunEvent, ShootEvent,
+    };
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct RunSnapshot {
+        state: GameState,
+        score: u32,
+        experience: u32,
+        spawn_timer_elapsed: f32,
+        camera_translation: Vec3,
+        players: usize,
+        enemies: usize,
+        run_entities: usize,
+        pending_shots: usize,
+        pending_damage: usize,
+        pending_level_ups: usize,
+        queued_level_ups: u32,
+        upgrade_stacks: Vec<(String, u32)>,
+        banished_upgrades: usize,
+        upgrade_offer: Vec<UpgradeId>,
+        upgrade_charges: UpgradeCharges,
+        gold: u32,
+        freeze: f32,
+        pending_chests: u32,
+        boss_schedule: (f32, u32, u32),
+        run_time: f32,
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            WindowPlugin::default(),
+            InputPlugin,
+            GameplayPlugin,
+            UiPlugin,
+        ))
+        .init_resource::<Settings>();
+        app.update();
+        app
+    }
+
+    fn start_run(app: &mut App) {
+        app.world.send_event(NewRunEvent);
+        app.update();
+        app.update();
+    }
+
+    fn snapshot(app: &mut App) -> RunSnapshot {
+        let world = &mut app.world;
+        RunSnapshot {
+            state: world.resource::<State<GameState>>().get().clone(),
+            score: world.resource::<PlayerScore>().0,
+            experience: world.resource::<PlayerExperience>().0,
+            spawn_timer_elapsed: world.resource::<EnemySpawnTimer>().0.elapsed_secs(),
+            camera_translation: world
+                .query_filtered::<&Transform, With<MainCamera>>()
+                .single(world)
+                .translation,
+            players: world.query::<&Player>().iter(world).count(),
+            enemies: world.query::<&Enemy>().iter(world).count(),
+            run_entities: world.query::<&RemoveOnReset>().iter(world).count(),
+            pending_shots: world.resource::<Events<ShootEvent>>().len(),
+            pending_damage: world.resource::<Events<DamageEvent>>().len(),
+            pending_level_ups: world.resource::<Events<LevelUpEvent>>().len(),
+            queued_level_ups: world.resource::<PendingLevelUps>().0,
+            upgrade_stacks: {
+                let mut stacks: Vec<(String, u32)> = world
+                    .resource::<UpgradeStacks>()
+                    .0
+                    .iter()
+                    .map(|(id, count)| (id.0.clone(), *count))
+                    .collect();
+                stacks.sort();
+                stacks
+            },
+            banished_upgrades: world.resource::<BanishedUpgrades>().0.len(),
+            upgrade_offer: world.resource::<UpgradeOffer>().0.clone(),
+            upgrade_charges: *world.resource::<UpgradeCharges>(),
+            gold: world.resource::<PlayerGold>().0,
+            freeze: world.resource::<EnemyFreeze>().0,
+            pending_chests: world.resource::<PendingChests>().0,
+            boss_schedule: {
+                let schedule = world.resource::<BossSchedule>();
+                (
+                    schedule.timer.elapsed_secs(),
+                    schedule.next_score,
+                    schedule.spawned,
+                )
+            },
+            run_time: world.resource::<RunClock>().0.elapsed_secs(),
+        }
+    }
+
+    #[test]
+    fn new_run_resets_world_to_fresh_start() {
+        let mut app = test_app();
+        start_run(&mut app);
+        let fresh = snapshot(&mut app);
+        assert_eq!(fresh.state, GameState::Starting);
+        assert_eq!(fresh.players, 1);
+
+        app.update();
+        let world = &mut app.world;
+        world.resource_mut::<PlayerScore>().0 = 120;
+        world.resource_mut::<PlayerExperience>().0 = 40;
+        world.resource_mut::<PendingLevelUps>().0 = 2;
+        world
+            .resource_mut::<UpgradeStacks>()
+            .0
+            .insert(UpgradeId("speed".to_string()), 2);
+        world
+            .resource_mut::<BanishedUpgrades>()
+            .0
+            .insert(UpgradeId("damage".to_string()));
+        world
+            .resource_mut::<UpgradeOffer>()
+            .0
+            .push(UpgradeId("health".to_string()));
+        world.resource_mut::<UpgradeCharges>().rerolls = 0;
+        world.resource_mut::<PlayerGold>().0 = 7;
+        world.resource_mut::<EnemyFreeze>().0 = 3.;
+        world.resource_mut::<PendingChests>().0 = 1;
+        {
+            let mut schedule = world.resource_mut::<BossSchedule>();
+            schedule.timer.tick(std::time::Duration::from_secs(40));
+            schedule.next_score = 3000;
+            schedule.spawned = 1;
+        }
+        world
+            .resource_mut::<RunClock>()
+            .0
+            .tick(std::time::Duration::from_secs(90));
+        world
+            .resource_mut::<EnemySpawnTimer>()
+            .0
+            .tick(std::time::Duration::from_secs_f32(0.3));
+        world
+            .query_filtered::<&mut Transform, With<MainCamera>>()
+            .single_mut(world)
+            .translation = Vec3::new(300., -150., 0.);
+        world.spawn_batch((0..5).map(|_| EnemyBundle::default()));
+        world.send_event(LevelUpEvent);
+        world.send_event(ShootEvent {
+            source: Vec2::ZERO,
+            target: Vec2::X,
+            damage: Damage(5),
+            shooter: Shooter::Enemy,
+            bullet_speed: ShotSpeed(300.),
+            critical: Critical(false),
+        });
+        assert_ne!(snapshot(&mut app), fresh);
+        let old_seed = app.world.resource::<RunSeed>().0;
+
+        start_run(&mut app);
+        assert_eq!(snapshot(&mut app), fresh);
+        // A fresh start means a fresh world, so the seed is rolled again rather than restored
+        assert_ne!(app.world.resource::<RunSeed>().0, old_seed);
+    }
+
+    fn pick_upgrade(app: &mut App, id: &str) {
+        app.world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Upgrading);
+        app.update();
+        app.world.send_event(UpgradeStatEvent {
+            id: UpgradeId(id.to_string()),
+        });
+        app.update();
+        app.update();
+    }
+
+    fn player_weapons(app: &mut App) -> Vec<(WeaponKind, u32)> {
+        app.world
+            .query::<&Weapon>()
+            .iter(&app.world)
+            .map(|weapon| (weapon.kind, weapon.level))
+            .collect()
+    }
+
+    #[test]
+    fn splitter_breaks_into_pieces_once() {
+        let mut app = test_app();
+        start_run(&mut app);
+        app.update();
+
+        let splitter = app
+            .world
+            .spawn(SplitterEnemyBundle::default().with_transform(Transform::from_xyz(500., 0., 0.)))
+            .id();
+        app.world.get_mut::<Health>(splitter).unwrap().0 = 0;
+        (0..3).for_each(|_| app.update());
+
+        assert!(app.world.get_entity(splitter).is_none());
+        let generations: Vec<u32> = app
+            .world
+            .query::<&SplitOnDeath>()
+            .iter(&app.world)
+            .map(|split| split.generations)
+            .collect();
+        assert_eq!(generations, vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn stun_cancels_telegraphed_shot() {
+        let mut app = test_app();
+        start_run(&mut app);
+        app.update();
+
+        let shooter = app
+            .world
+            .spawn(ShooterEnemyBundle::default().with_transform(Transform::from_xyz(200., 0., 0.)))
+            .insert(Telegraphing(Timer::from_seconds(10., TimerMode::Once)))
+            .with_children(|parent| {
+                parent.spawn((TelegraphIndicator, SpriteBundle::default()));
+            })
+            .id();
+        app.update();
+        app.world
+            .entity_mut(shooter)
+            .insert(Stunned(Timer::from_seconds(10., TimerMode::Once)));
+        app.update();
+
+        assert!(app.world.get::<Telegraphing>(shooter).is_none());
+        let indicators = app
+            .world
+            .query::<&TelegraphIndicator>()
+            .iter(&app.world)
+            .count();
+        assert_eq!(indicators, 0);
+    }
+
+    #[test]
+    fn boss_winds_up_before_attacking_and_stun_interrupts() {
+        let mut app = test_app();
+        start_run(&mut app);
+        app.update();
+
+        let boss = app
+            .world
+            .spawn(BossBundle::new(
+                BossKind::Juggernaut,
+                Transform::from_xyz(300., 0., 0.),
+            ))
+            .id();
+        app.world.get_mut::<Boss>(boss).unwrap().attack =
+            Timer::from_seconds(0.001, TimerMode::Repeating);
+        (0..2).for_each(|_| app.update());
+
+        assert!(app.world.get::<Telegraphing>(boss).is_some());
+        assert!(app.world.get::<Charging>(boss).is_none());
+
+        app.world
+            .entity_mut(boss)
+            .insert(Stunned(Timer::from_seconds(10., TimerMode::Once)));
+        app.update();
+
+        assert!(app.world.get::<Telegraphing>(boss).is_none());
+        assert!(app.world.get::<Charging>(boss).is_none());
+    }
+
+    #[test]
+    fn arena_layout_keeps_obstacles_apart_and_inside() {
+        let config = ArenaConfig::default();
+        let layout = generate_layout(&config, &mut StdRng::seed_from_u64(7));
+        assert_eq!(layout.len(), config.obstacles);
+
+        let boxes: Vec<Aabb2d> = layout
+            .iter()
+            .map(|(kind, center)| Aabb2d::new(*center, kind.size() / 2.))
+            .collect();
+        layout.iter().zip(&boxes).for_each(|((_, center), bounds)| {
+            assert!(center.length() >= config.clear_radius);
+            assert!(bounds.min.cmpge(-config.half_extents).all());
+            assert!(bounds.max.cmple(config.half_extents).all());
+        });
+        boxes.iter().enumerate().for_each(|(i, a)| {
+            assert!(boxes[i + 1..].iter().all(|b| !a.intersects(b)));
+        });
+    }
+
+    fn chunk_coords(app: &mut App) -> Vec<IVec2> {
+        app.world
+            .query::<&Chunk>()
+            .iter(&app.world)
+            .map(|chunk| chunk.0)
+            .collect()
+    }
+
+    /// Every sprite drawn on the chunk at `coord`, in spawn order.
+    fn chunk_sprites(app: &mut App, coord: IVec2) -> Vec<(Transform, Option<Vec2>, Color)> {
+        let children: Vec<Entity> = app
+            .world
+            .query::<(&Chunk, &Children)>()
+            .iter(&app.world)
+            .filter(|(chunk, _)| chunk.0 == coord)
+            .flat_map(|(_, children)| children.iter().copied())
+            .collect();
+        children
+            .into_iter()
+            .map(|child| {
+                let sprite = app.world.get::<Sprite>(child).unwrap();
+                (
+                    *app.world.get::<Transform>(child).unwrap(),
+                    sprite.custom_size,
+                    sprite.color,
+                )
+            })
+            .collect()
+    }
+
+    /// Puts the player and the camera following them at `position`.
+    fn move_view(app: &mut App, position: Vec3) {
+        app.world
+            .query_filtered::<&mut Transform, Or<(With<Player>, With<MainCamera>)>>()
+            .iter_mut(&mut app.world)
+            .for_each(|mut transform| transform.translation = position);
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn background_streams_with_camera() {
+        let mut app = test_app();
+        start_run(&mut app);
+        app.update();
+        let around_origin = chunk_coords(&mut app);
+        assert!(around_origin.contains(&IVec2::ZERO));
+
+        let far = Vec3::new(20_000., 0., 0.);
+        move_view(&mut app, far);
+
+        let moved = chunk_coords(&mut app);
+        assert!(moved.contains(&chunk_of(far.xy())));
+        assert!(!moved.contains(&IVec2::ZERO));
+    }
+
+    #[test]
+    fn background_rebuilds_identically_from_seed() {
+        let mut app = test_app();
+        start_run(&mut app);
+        app.update();
+        let original = chunk_sprites(&mut app, IVec2::ZERO);
+        assert!(!original.is_empty());
+
+        let far = Vec3::new(20_000., 0., 0.);
+        move_view(&mut app, far);
+        assert!(chunk_sprites(&mut app, IVec2::ZERO).is_empty());
+        move_view(&mut app, Vec3::ZERO);
+        assert_eq!(chunk_sprites(&mut app, IVec2::ZERO), original);
+
+        app.world.resource_mut::<RunSeed>().0 ^= 1;
+        move_view(&mut app, far);
+        move_view(&mut app, Vec3::ZERO);
+        assert_ne!(chunk_sprites(&mut app, IVec2::ZERO), original);
+    }
+
+    #[test]
+    fn bomb_spares_bosses() {
+        let mut app = test_app();
+        start_run(&mut app);
+        app.update();
+
+        let boss = app
+            .world
+            .spawn(BossBundle::new(
+                BossKind::Juggernaut,
+                Transform::from_xyz(150., 0., 0.),
+            ))
+            .id();
+        let grunt = app
+            .world
+            .spawn(EnemyBundle::default().with_transform(Transform::from_xyz(-150., 0., 0.)))
+            .id();
+        app.update();
+        app.world.send_event(PickupEvent(PickupKind::Bomb));
+        (0..2).for_each(|_| app.update());
+
+        assert!(app.world.get_entity(grunt).is_none());
+        assert!(app.world.get::<Boss>(boss).is_some());
+        assert!(app.world.get::<Health>(boss).unwrap().0 > 0);
+    }
+
+    fn load_base_registry(app: &mut App) {
+        let registry: UpgradeRegistry =
+            ron::de::from_bytes(&std::fs::read("assets/base.upgrades.ron").unwrap()).unwrap();
+        let handle = app
+            .world
+            .resource_mut::<Assets<UpgradeRegistry>>()
+            .add(registry);
+        app.world.insert_resource(UpgradeRegistryHandle(handle));
+    }
+
+    #[test]
+    fn boss_chest_offers_ready_evolution() {
+        let mut app = test_app();
+        load_base_registry(&mut app);
+        start_run(&mut app);
+        app.update();
+
+        (0..5).for_each(|_| pick_upgrade(&mut app, "blades"));
+        pick_upgrade(&mut app, "plating");
+        let blade_storm = UpgradeId("blade_storm".to_string());
+        app.world
+            .resource_mut::<BanishedUpgrades>()
+            .0
+            .insert(blade_storm.clone());
+
+        assert_eq!(PickupKind::Chest.lifetime(), None);
+        app.world.send_event(PickupEvent(PickupKind::Chest));
+        (0..3).for_each(|_| app.update());
+
+        assert_eq!(
+            app.world.resource::<State<GameState>>().get(),
+            &GameState::Upgrading
+        );
+        assert!(app
+            .world
+            .resource::<UpgradeOffer>()
+            .0
+            .contains(&blade_storm));
+    }
+
+    #[test]
+    fn full_recipe_evolves_weapon() {
+        let mut app = test_app();
+        load_base_registry(&mut app);
+        start_run(&mut app);
+        app.update();
+
+        (0..5).for_each(|_| pick_upgrade(&mut app, "blades"));
+        assert_eq!(
+            player_weapons(&mut app),
+            vec![(WeaponKind::OrbitingBlades, 5)]
+        );
+
+        pick_upgrade(&mut app, "plating");
+        let ready: Vec<String> = {
+            let registries = app.world.resource::<Assets<UpgradeRegistry>>();
+            let registry = registries
+                .get(&app.world.resource::<UpgradeRegistryHandle>().0)
+                .unwrap();
+            registry
+                .ready_evolutions(&app.world.resource::<UpgradeStacks>().0)
+                .iter()
+                .map(|upgrade| upgrade.id.0.clone())
+                .collect()
+        };
+        assert_eq!(ready, vec!["blade_storm".to_string()]);
+
+        pick_upgrade(&mut app, "blade_storm");
+        assert_eq!(player_weapons(&mut app), vec![(WeaponKind::BladeStorm, 1)]);
+        assert_eq!(
+            app.world.query::<&OrbitingBlade>().iter(&app.world).count(),
+            WeaponKind::BladeStorm.stats(1).count as usize
+        );
     }
 }
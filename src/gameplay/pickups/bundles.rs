@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::RemoveOnReset;
+
+use super::components::{Pickup, PickupKind};
+
+#[derive(Bundle)]
+pub struct PickupBundle {
+    sprite: SpriteBundle,
+    remove_on_reset: RemoveOnReset,
+    pickup: Pickup,
+}
+
+impl PickupBundle {
+    pub fn new(kind: PickupKind, transform: Transform) -> Self {
+        Self {
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: kind.color(),
+                    custom_size: Some(Vec2::splat(kind.size())),
+                    ..Default::default()
+                },
+                transform,
+                ..default()
+            },
+            remove_on_reset: RemoveOnReset,
+            pickup: Pickup(kind),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PickupKind {
+    HealthOrb,
+    Bomb,
+    Magnet,
+    Freeze,
+    Gold,
+    /// Boss reward that opens an extra upgrade choice, guaranteed to include a
+    /// ready evolution. It never expires.
+    Chest,
+}
+
+impl PickupKind {
+    pub fn color(&self) -> Color {
+        match self {
+            PickupKind::HealthOrb => Color::rgb(1.0, 0.3, 0.4),
+            PickupKind::Bomb => Color::rgb(0.2, 0.2, 0.2),
+            PickupKind::Magnet => Color::rgb(0.3, 0.5, 1.0),
+            PickupKind::Freeze => Color::rgb(0.7, 0.95, 1.0),
+            PickupKind::Gold => Color::GOLD,
+            PickupKind::Chest => Color::rgb(0.8, 0.5, 0.2),
+        }
+    }
+
+    pub fn size(&self) -> f32 {
+        match self {
+            PickupKind::Gold => 12.,
+            PickupKind::Chest => 28.,
+            _ => 16.,
+        }
+    }
+
+    pub fn lifetime(&self) -> Option<f32> {
+        match self {
+            PickupKind::Chest => None,
+            _ => Some(12.),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Pickup(pub PickupKind);
+
+/// Despawns the pickup when finished; it starts blinking shortly before.
+#[derive(Component)]
+pub struct PickupLifetime(pub Timer);
+
+/// Weighted loot for an enemy archetype; a `None` entry means nothing drops.
+#[derive(Component, Clone)]
+pub struct DropTable(pub &'static [(Option<PickupKind>, u32)]);
+
+pub const BASIC_DROPS: DropTable = DropTable(&[
+    (None, 940),
+    (Some(PickupKind::Gold), 40),
+    (Some(PickupKind::HealthOrb), 10),
+    (Some(PickupKind::Magnet), 5),
+    (Some(PickupKind::Freeze), 3),
+    (Some(PickupKind::Bomb), 2),
+]);
+
+pub const SHOOTER_DROPS: DropTable = DropTable(&[
+    (None, 800),
+    (Some(PickupKind::Gold), 120),
+    (Some(PickupKind::HealthOrb), 40),
+    (Some(PickupKind::Magnet), 15),
+    (Some(PickupKind::Freeze), 15),
+    (Some(PickupKind::Bomb), 10),
+]);
@@ -0,0 +1,6 @@
+use bevy::prelude::Event;
+
+use super::components::PickupKind;
+
+#[derive(Event)]
+pub struct PickupEvent(pub PickupKind);
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{
+    player::crumbs::systems::attract_items,
+    states::GameState,
+    system_sets::{GameplaySet, RunSet},
+    systems::clear_events,
+};
+
+use self::{
+    components::Pickup,
+    events::PickupEvent,
+    resources::{EnemyFreeze, PendingChests, PlayerGold},
+    systems::{
+        apply_pickups, collect_pickups, drop_loot, reset_pickups, tick_enemy_freeze,
+        tick_pickup_lifetimes,
+    },
+};
+
+pub mod bundles;
+pub mod components;
+pub mod events;
+pub mod resources;
+pub mod systems;
+
+pub struct PickupsPlugin;
+
+impl Plugin for PickupsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerGold>()
+            .init_resource::<EnemyFreeze>()
+            .init_resource::<PendingChests>()
+            .add_event::<PickupEvent>()
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (reset_pickups, clear_events::<PickupEvent>).in_set(RunSet::Reset),
+            )
+            .add_systems(
+                Update,
+                (
+                    drop_loot,
+                    tick_enemy_freeze,
+                    (
+                        attract_items::<Pickup>,
+                        tick_pickup_lifetimes,
+                        collect_pickups,
+                        apply_pickups,
+                    )
+                        .chain()
+                        .in_set(GameplaySet::Player),
+                )
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
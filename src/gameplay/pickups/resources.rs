@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct PlayerGold(pub u32);
+
+/// Seconds left during which enemies stand still.
+#[derive(Resource, Default)]
+pub struct EnemyFreeze(pub f32);
+
+/// Opened chests whose upgrade menu hasn't come up yet; each one guarantees a
+/// ready evolution in the next offer.
+#[derive(Resource, Default)]
+pub struct PendingChests(pub u32);
+
+impl EnemyFreeze {
+    pub fn active(&self) -> bool {
+        self.0 > 0.
+    }
+}
@@ -0,0 +1,220 @@
+use bevy::{
+    math::bounding::{Aabb2d, IntersectsVolume},
+    prelude::*,
+    window::PrimaryWindow,
+};
+use rand::{seq::SliceRandom, thread_rng, Rng};
+
+use crate::{
+    gameplay::{
+        components::{Critical, Dead, Health, Luck, MainCamera, MaxHealth, Shooter},
+        enemies::{boss::components::Boss, components::Enemy},
+        player::{
+            components::Player,
+            crumbs::{
+                components::{Attracted, ExpCrumb},
+                systems::CRUMB_START_SPEED,
+            },
+            resources::PendingLevelUps,
+        },
+    },
+    DamageEvent,
+};
+
+use super::{
+    bundles::PickupBundle,
+    components::{DropTable, Pickup, PickupKind, PickupLifetime},
+    events::PickupEvent,
+    resources::{EnemyFreeze, PendingChests, PlayerGold},
+};
+
+const PICKUP_BLINK_TIME: f32 = 3.;
+const PICKUP_BLINK_PERIOD: f32 = 0.15;
+const HEALTH_ORB_HEAL: i32 = 10;
+const FREEZE_DURATION: f32 = 5.;
+
+pub fn drop_loot(
+    q_dead: Query<(&Transform, &Health, &DropTable), (With<Enemy>, Changed<Health>)>,
+    q_player: Query<&Luck, With<Player>>,
+    mut commands: Commands,
+) {
+    let luck = q_player.get_single().map_or(0., |luck| luck.0);
+    let mut rng = thread_rng();
+    q_dead
+        .iter()
+        .filter(|(_, health, _)| health.0 <= 0)
+        .for_each(|(tr, _, table)| {
+            let roll = table
+                .0
+                .choose_weighted(&mut rng, |(kind, weight)| match kind {
+                    Some(_) => *weight as f32 * (1. + luck),
+                    None => *weight as f32,
+                });
+            if let Ok((Some(kind), _)) = roll {
+                let offset = Vec2::from_angle(rng.gen_range(0. ..std::f32::consts::TAU)) * 16.;
+                let mut pickup = commands.spawn(PickupBundle::new(
+                    *kind,
+                    tr.with_translation(tr.translation + offset.extend(0.)),
+                ));
+                if let Some(seconds) = kind.lifetime() {
+                    pickup.insert(PickupLifetime(Timer::from_seconds(
+                        seconds,
+                        TimerMode::Once,
+                    )));
+                }
+            }
+        });
+}
+
+pub fn tick_pickup_lifetimes(
+    mut q_pickups: Query<(
+        &mut PickupLifetime,
+        &mut Visibility,
+        Option<&Attracted>,
+        Entity,
+    )>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    q_pickups
+        .iter_mut()
+        .for_each(|(mut lifetime, mut visibility, attracted, entity)| {
+            // Once it is flying to the player it can no longer expire
+            if attracted.is_some() {
+                *visibility = Visibility::Visible;
+                return;
+            }
+            if lifetime.0.tick(time.delta()).finished() {
+                commands.entity(entity).despawn();
+                return;
+            }
+            let remaining = lifetime.0.remaining_secs();
+            *visibility = if remaining < PICKUP_BLINK_TIME
+                && ((remaining / PICKUP_BLINK_PERIOD) as u32).is_multiple_of(2)
+            {
+                Visibility::Hidden
+            } else {
+                Visibility::Visible
+            };
+        });
+}
+
+pub fn collect_pickups(
+    q_player: Query<(&Transform, &Sprite), With<Player>>,
+    q_pickups: Query<(&Transform, &Sprite, &Pickup, Entity)>,
+    mut ev_pickup: EventWriter<PickupEvent>,
+    mut commands: Commands,
+) {
+    if let Ok((player_tr, player_sprite)) = q_player.get_single() {
+        let player_box = Aabb2d::new(
+            player_tr.translation.xy(),
+            player_sprite.custom_size.unwrap() * 0.5,
+        );
+
+        q_pickups
+            .iter()
+            .for_each(|(pickup_tr, sprite, pickup, entity)| {
+                let pickup_box = Aabb2d::new(
+                    pickup_tr.translation.truncate(),
+                    sprite.custom_size.unwrap() * 0.5,
+                );
+
+                if player_box.intersects(&pickup_box) {
+                    commands.entity(entity).despawn();
+                    ev_pickup.send(PickupEvent(pickup.0));
+                }
+            })
+    }
+}
+
+pub fn apply_pickups(
+    mut ev_pickup: EventReader<PickupEvent>,
+    mut q_player: Query<(&mut Health, &MaxHealth), With<Player>>,
+    mut q_enemies: Query<
+        (&Transform, &mut Health, Entity),
+        // Bosses are meant to be fought through their phases, not bombed
+        (With<Enemy>, Without<Boss>, Without<Dead>, Without<Player>),
+    >,
+    q_crumbs: Query<Entity, (With<ExpCrumb>, Without<Attracted>)>,
+    q_windows: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<&Transform, With<MainCamera>>,
+    mut freeze: ResMut<EnemyFreeze>,
+    mut gold: ResMut<PlayerGold>,
+    mut pending: ResMut<PendingLevelUps>,
+    mut chests: ResMut<PendingChests>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    mut commands: Commands,
+) {
+    ev_pickup.read().for_each(|PickupEvent(kind)| match kind {
+        PickupKind::HealthOrb => {
+            if let Ok((mut health, max_health)) = q_player.get_single_mut() {
+                health.heal(HEALTH_ORB_HEAL, max_health);
+            }
+        }
+        PickupKind::Bomb => {
+            let (Ok(window), Ok(camera_tr)) = (q_windows.get_single(), q_camera.get_single())
+            else {
+                return;
+            };
+            let center = camera_tr.translation.xy();
+            let half_screen = Vec2::new(window.width(), window.height()) * 0.5;
+            q_enemies
+                .iter_mut()
+                .filter(|(enemy_tr, health, _)| {
+                    health.0 > 0
+                        && (enemy_tr.translation.xy() - center)
+                            .abs()
+                            .cmple(half_screen)
+                            .all()
+                })
+                .for_each(|(enemy_tr, mut health, entity)| {
+                    let amount = health.0;
+                    health.0 = 0;
+                    ev_damage.send(DamageEvent {
+                        target: entity,
+                        position: enemy_tr.translation.xy(),
+                        amount,
+                        dealt_by: Shooter::Player,
+                        critical: Critical(false),
+                    });
+                });
+        }
+        PickupKind::Magnet => {
+            q_crumbs.iter().for_each(|crumb| {
+                commands.entity(crumb).insert(Attracted {
+                    speed: CRUMB_START_SPEED,
+                });
+            });
+        }
+        PickupKind::Freeze => {
+            freeze.0 = FREEZE_DURATION;
+        }
+        PickupKind::Gold => {
+            gold.0 += thread_rng().gen_range(1..=5);
+        }
+        PickupKind::Chest => {
+            pending.0 += 1;
+            chests.0 += 1;
+        }
+    });
+}
+
+pub fn tick_enemy_freeze(mut freeze: ResMut<EnemyFreeze>, time: Res<Time>) {
+    if freeze.active() {
+        freeze.0 = (freeze.0 - time.delta_seconds()).max(0.);
+    }
+}
+
+pub fn enemies_unfrozen(freeze: Res<EnemyFreeze>) -> bool {
+    !freeze.active()
+}
+
+pub fn reset_pickups(
+    mut gold: ResMut<PlayerGold>,
+    mut freeze: ResMut<EnemyFreeze>,
+    mut chests: ResMut<PendingChests>,
+) {
+    *gold = PlayerGold::default();
+    *freeze = EnemyFreeze::default();
+    *chests = PendingChests::default();
+}
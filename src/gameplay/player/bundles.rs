@@ -1,17 +1,18 @@
-use core::time;
-
 use bevy::{prelude::*, time::Stopwatch};
 
 use crate::gameplay::{
     bundles::ShooterBundle,
     components::{
-        Damage, Health, ReloadStopwatch, ReloadTime, RemoveOnReset, Shooter, ShotSpeed, Speed,
+        Armor, CooldownReduction, CritChance, Damage, DamageReduction, Health, Luck, MaxHealth,
+        Regen, ReloadStopwatch, ReloadTime, RemoveOnReset, Shield, Shooter, ShotSpeed, Speed,
     },
+    stats::components::{Stat, Stats},
 };
 
 use super::{
     components::{Player, PlayerLevel},
     crumbs::components::{CrumbCollectRadius, ExpGain},
+    resources::ExperienceCurve,
 };
 
 #[derive(Bundle)]
@@ -19,40 +20,80 @@ pub struct PlayerBundle {
     speed: Speed,
     marker: Player,
     hp: Health,
+    max_hp: MaxHealth,
     shooter_marker: Shooter,
     shooter: ShooterBundle,
     level: PlayerLevel,
     sprite: SpriteBundle,
     remove_on_reset: RemoveOnReset,
     collect_radius: CrumbCollectRadius,
+    stats: Stats,
+    armor: Armor,
+    regen: Regen,
+    luck: Luck,
+    cooldown_reduction: CooldownReduction,
+    shield: Shield,
+    damage_reduction: DamageReduction,
 }
 
-impl Default for PlayerBundle {
-    fn default() -> Self {
+impl PlayerBundle {
+    pub fn new(curve: &ExperienceCurve) -> Self {
         let start_pos = Transform::default();
         let sprite_size = Vec2::splat(32.);
-        let player_hp = 30;
+        let stats = Stats::new([
+            (Stat::Health, 30.),
+            (Stat::Speed, 125.),
+            (Stat::Damage, 5.),
+            (Stat::ReloadTime, 0.25),
+            (Stat::ShotSpeed, 500.),
+            (Stat::CritChance, 0.1),
+            (Stat::CollectRadius, 200.),
+            (Stat::Armor, 0.),
+            (Stat::Regen, 0.),
+            (Stat::Luck, 0.),
+            (Stat::CooldownReduction, 0.),
+            (Stat::Shield, 0.),
+            (Stat::DamageReduction, 0.),
+        ]);
         Self {
-            speed: Speed(125.),
+            speed: Speed(stats.value(Stat::Speed)),
             marker: Player,
-            hp: Health(player_hp),
+            hp: Health(stats.value(Stat::Health) as i32),
+            max_hp: MaxHealth(stats.value(Stat::Health) as i32),
             shooter_marker: Shooter::Player,
-            collect_radius: CrumbCollectRadius(200.),
+            collect_radius: CrumbCollectRadius(stats.value(Stat::CollectRadius)),
             level: PlayerLevel {
                 level: 1,
-                next_level_delta: ExpGain(20),
+                next_level_delta: ExpGain(curve.required(1)),
             },
             shooter: ShooterBundle {
-                damage: Damage(5),
-                reload_time: ReloadTime(time::Duration::from_secs_f32(0.25)),
+                damage: Damage(stats.value(Stat::Damage) as i32),
+                reload_time: ReloadTime(std::time::Duration::from_secs_f32(
+                    stats.value(Stat::ReloadTime),
+                )),
                 since_last_reload: ReloadStopwatch(
                     Stopwatch::new()
                         .tick(std::time::Duration::from_secs_f32(0.25))
                         .clone(),
                 ),
-                shot_speed: ShotSpeed(500.),
+                shot_speed: ShotSpeed(stats.value(Stat::ShotSpeed)),
+                crit_chance: CritChance(stats.value(Stat::CritChance)),
             },
             remove_on_reset: RemoveOnReset,
+            armor: Armor(stats.value(Stat::Armor)),
+            regen: Regen {
+                rate: stats.value(Stat::Regen),
+                progress: 0.,
+            },
+            luck: Luck(stats.value(Stat::Luck)),
+            cooldown_reduction: CooldownReduction(stats.value(Stat::CooldownReduction)),
+            shield: Shield {
+                current: stats.value(Stat::Shield),
+                max: stats.value(Stat::Shield),
+                since_hit: 0.,
+            },
+            damage_reduction: DamageReduction(stats.value(Stat::DamageReduction)),
+            stats,
             sprite: SpriteBundle {
                 sprite: Sprite {
                     color: Color::GREEN,
@@ -10,3 +10,7 @@ pub struct PlayerLevel {
     pub level: u32,
     pub next_level_delta: ExpGain,
 }
+
+/// One edge of the outline drawn around the player while their shield holds.
+#[derive(Component)]
+pub struct ShieldRing;
@@ -12,25 +12,20 @@ pub struct ExpCrumbBundle {
     crumb: ExpCrumb,
 }
 
-impl Default for ExpCrumbBundle {
-    fn default() -> Self {
-        Self::with_transform(Transform::from_xyz(0., 0., 0.))
-    }
-}
-
 impl ExpCrumbBundle {
-    pub fn with_transform(transform: Transform) -> Self {
+    pub fn new(transform: Transform, gain: ExpGain) -> Self {
+        let (color, size) = gain.tier();
         Self {
             sprite: SpriteBundle {
                 sprite: Sprite {
-                    color: Color::YELLOW,
-                    custom_size: Some(Vec2::splat(10.)),
+                    color,
+                    custom_size: Some(Vec2::splat(size)),
                     ..Default::default()
                 },
                 transform,
                 ..default()
             },
-            gain: ExpGain(5),
+            gain,
             remove_on_reset: RemoveOnReset,
             crumb: ExpCrumb,
         }
@@ -3,8 +3,26 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct ExpGain(pub u32);
 
+impl ExpGain {
+    /// Sprite color and size for the crumb tier this much XP falls into.
+    pub fn tier(&self) -> (Color, f32) {
+        match self.0 {
+            0..=9 => (Color::YELLOW, 10.),
+            10..=49 => (Color::LIME_GREEN, 14.),
+            50..=199 => (Color::CYAN, 18.),
+            _ => (Color::FUCHSIA, 24.),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct ExpCrumb;
 
 #[derive(Component)]
 pub struct CrumbCollectRadius(pub f32);
+
+/// A crumb flying toward the player; it keeps accelerating until it touches them.
+#[derive(Component)]
+pub struct Attracted {
+    pub speed: f32,
+}
@@ -1,10 +1,18 @@
 use bevy::prelude::*;
 
-use crate::gameplay::states::GameState;
+use crate::gameplay::{
+    states::GameState,
+    system_sets::{GameplaySet, RunSet},
+    systems::clear_events,
+};
 
 use self::{
+    components::ExpCrumb,
     events::LevelUpEvent,
-    systems::{collect_crumbs, drop_crumbs, level_up},
+    systems::{
+        attract_items, collect_crumbs, drop_crumbs, gain_levels, level_up, merge_crumbs,
+        move_attracted_crumbs, open_pending_level_up,
+    },
 };
 
 pub mod bundles;
@@ -16,9 +24,27 @@ pub struct ExpCrumbPlugin;
 
 impl Plugin for ExpCrumbPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<LevelUpEvent>().add_systems(
-            Update,
-            (collect_crumbs, drop_crumbs, level_up).run_if(in_state(GameState::Running)),
-        );
+        app.add_event::<LevelUpEvent>()
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (clear_events::<LevelUpEvent>).in_set(RunSet::Reset),
+            )
+            .add_systems(
+                Update,
+                (
+                    (drop_crumbs, merge_crumbs).chain(),
+                    (
+                        attract_items::<ExpCrumb>,
+                        move_attracted_crumbs,
+                        collect_crumbs,
+                        gain_levels,
+                        level_up,
+                        open_pending_level_up,
+                    )
+                        .chain()
+                        .in_set(GameplaySet::Player),
+                )
+                    .run_if(in_state(GameState::Running)),
+            );
     }
 }
@@ -1,14 +1,15 @@
 use bevy::{
     math::bounding::{Aabb2d, IntersectsVolume},
     prelude::*,
+    utils::HashMap,
 };
 
 use crate::gameplay::{
-    components::Health,
+    components::{ExpDrop, Health},
     enemies::components::Enemy,
     player::{
         components::{Player, PlayerLevel},
-        resources::PlayerExperience,
+        resources::{ExperienceCurve, PendingLevelUps, PlayerExperience},
     },
     states::GameState,
 };
@@ -17,30 +18,80 @@ use bevy::math::bounding::BoundingCircle;
 
 use super::{
     bundles::ExpCrumbBundle,
-    components::{CrumbCollectRadius, ExpCrumb, ExpGain},
+    components::{Attracted, CrumbCollectRadius, ExpCrumb, ExpGain},
     events::LevelUpEvent,
 };
 
+pub const CRUMB_START_SPEED: f32 = 50.;
+const CRUMB_ACCELERATION: f32 = 1200.;
+/// Above this many crumbs, the ones farthest from the player get merged together.
+const MAX_CRUMBS: usize = 300;
+const MERGE_CELL_SIZE: f32 = 300.;
+
 pub fn drop_crumbs(
-    q_dead: Query<(&Transform, &Health), (With<Enemy>, Changed<Health>)>,
+    q_dead: Query<(&Transform, &Health, &ExpDrop), (With<Enemy>, Changed<Health>)>,
     mut commands: Commands,
 ) {
-    q_dead.iter().for_each(|(tr, health)| {
+    q_dead.iter().for_each(|(tr, health, exp_drop)| {
         if health.0 <= 0 {
-            commands.spawn(ExpCrumbBundle::with_transform(tr.clone()));
+            commands.spawn(ExpCrumbBundle::new(*tr, ExpGain(exp_drop.0)));
         }
     })
 }
 
+/// Starts pulling `T` items toward the player once they are inside the collect radius.
+pub fn attract_items<T: Component>(
+    q_player: Query<(&Transform, &CrumbCollectRadius), With<Player>>,
+    q_crumbs: Query<(&Transform, &Sprite, Entity), (With<T>, Without<Attracted>)>,
+    mut commands: Commands,
+) {
+    if let Ok((player_tr, player_radius)) = q_player.get_single() {
+        let player_radius = BoundingCircle::new(player_tr.translation.xy(), player_radius.0);
+
+        q_crumbs.iter().for_each(|(crumb_tr, sprite, entity)| {
+            let crumb_box = Aabb2d::new(
+                crumb_tr.translation.truncate(),
+                sprite.custom_size.unwrap() * 0.5,
+            );
+
+            if player_radius.intersects(&crumb_box) {
+                commands.entity(entity).insert(Attracted {
+                    speed: CRUMB_START_SPEED,
+                });
+            }
+        })
+    }
+}
+
+pub fn move_attracted_crumbs(
+    q_player: Query<&Transform, With<Player>>,
+    mut q_crumbs: Query<(&mut Transform, &mut Attracted), Without<Player>>,
+    time: Res<Time>,
+) {
+    if let Ok(player_tr) = q_player.get_single() {
+        let target = player_tr.translation.xy();
+        q_crumbs
+            .iter_mut()
+            .for_each(|(mut crumb_tr, mut attracted)| {
+                attracted.speed += CRUMB_ACCELERATION * time.delta_seconds();
+                let offset = target - crumb_tr.translation.xy();
+                let step = offset.clamp_length_max(attracted.speed * time.delta_seconds());
+                crumb_tr.translation += step.extend(0.);
+            })
+    }
+}
+
 pub fn collect_crumbs(
-    q_player: Query<(&Transform, &CrumbCollectRadius, &PlayerLevel), With<Player>>,
+    q_player: Query<(&Transform, &Sprite), With<Player>>,
     q_crumbs: Query<(&Transform, &ExpGain, &Sprite, Entity), With<ExpCrumb>>,
     mut exp: ResMut<PlayerExperience>,
-    mut lvl_events: EventWriter<LevelUpEvent>,
     mut commands: Commands,
 ) {
-    if let Ok((player_tr, player_radius, player_level)) = q_player.get_single() {
-        let player_radius = BoundingCircle::new(player_tr.translation.xy(), player_radius.0);
+    if let Ok((player_tr, player_sprite)) = q_player.get_single() {
+        let player_box = Aabb2d::new(
+            player_tr.translation.xy(),
+            player_sprite.custom_size.unwrap() * 0.5,
+        );
 
         q_crumbs
             .iter()
@@ -50,33 +101,192 @@ pub fn collect_crumbs(
                     sprite.custom_size.unwrap() * 0.5,
                 );
 
-                if player_radius.intersects(&crumb_box) {
-                    (*exp).0 += exp_gain.0;
+                if player_box.intersects(&crumb_box) {
+                    exp.0 += exp_gain.0;
                     commands.entity(entity).despawn();
                 }
-
-                if player_level.next_level_delta.0 <= exp.0 {
-                    lvl_events.send(LevelUpEvent);
-                }
             })
     }
 }
 
-pub fn level_up(
-    mut lvl_events: EventReader<LevelUpEvent>,
+pub fn merge_crumbs(
+    q_player: Query<&Transform, With<Player>>,
+    mut q_crumbs: Query<
+        (&mut Transform, &mut ExpGain, &mut Sprite, Entity),
+        (With<ExpCrumb>, Without<Attracted>, Without<Player>),
+    >,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    let count = q_crumbs.iter().count();
+    if count <= MAX_CRUMBS {
+        return;
+    }
+    let player_pos = player_tr.translation.xy();
+    let mut far: Vec<(Vec2, u32, Entity)> = q_crumbs
+        .iter()
+        .map(|(tr, gain, _, entity)| (tr.translation.xy(), gain.0, entity))
+        .collect();
+    far.sort_by(|a, b| {
+        b.0.distance_squared(player_pos)
+            .total_cmp(&a.0.distance_squared(player_pos))
+    });
+    far.truncate((count - MAX_CRUMBS) * 2);
+
+    let mut cells: HashMap<IVec2, Vec<(Vec2, u32, Entity)>> = HashMap::new();
+    far.iter().for_each(|crumb| {
+        let cell = (crumb.0 / MERGE_CELL_SIZE).floor().as_ivec2();
+        cells.entry(cell).or_default().push(*crumb);
+    });
+    let mut groups: Vec<Vec<(Vec2, u32, Entity)>> = cells
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .collect();
+    let merged: usize = groups.iter().map(|group| group.len() - 1).sum();
+    if count - merged > MAX_CRUMBS {
+        // Too spread out for the grid to help, so fold all of them into one
+        groups = vec![far];
+    }
+
+    groups.into_iter().for_each(|group| {
+        let total: u32 = group.iter().map(|(_, value, _)| value).sum();
+        let center = group
+            .iter()
+            .map(|(position, value, _)| *position * *value as f32)
+            .sum::<Vec2>()
+            / total.max(1) as f32;
+        let (_, _, survivor) = group[0];
+        group.iter().skip(1).for_each(|(_, _, entity)| {
+            commands.entity(*entity).despawn();
+        });
+        if let Ok((mut tr, mut gain, mut sprite, _)) = q_crumbs.get_mut(survivor) {
+            gain.0 = total;
+            let (color, size) = gain.tier();
+            sprite.color = color;
+            sprite.custom_size = Some(Vec2::splat(size));
+            tr.translation = center.extend(tr.translation.z);
+        }
+    });
+}
+
+pub fn gain_levels(
     mut q_player: Query<&mut PlayerLevel, With<Player>>,
-    mut game_state: ResMut<NextState<GameState>>,
-    mut player_exp: ResMut<PlayerExperience>,
+    mut exp: ResMut<PlayerExperience>,
+    curve: Res<ExperienceCurve>,
+    mut lvl_events: EventWriter<LevelUpEvent>,
 ) {
     if let Ok(mut level) = q_player.get_single_mut() {
-        if !lvl_events.is_empty() {
-            lvl_events.read().take(1).for_each(|_| {
-                game_state.set(GameState::Upgrading);
-
-                level.level += 1;
-                level.next_level_delta.0 += 5 * level.next_level_delta.0.ilog2();
-                player_exp.0 = 0;
-            });
+        while level.next_level_delta.0 <= exp.0 {
+            exp.0 -= level.next_level_delta.0;
+            level.level += 1;
+            level.next_level_delta.0 = curve.required(level.level);
+            lvl_events.send(LevelUpEvent);
         }
     }
 }
+
+pub fn level_up(mut lvl_events: EventReader<LevelUpEvent>, mut pending: ResMut<PendingLevelUps>) {
+    pending.0 += lvl_events.read().count() as u32;
+}
+
+pub fn open_pending_level_up(
+    mut pending: ResMut<PendingLevelUps>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    if pending.0 > 0 {
+        pending.0 -= 1;
+        game_state.set(GameState::Upgrading);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leveling_app(level: u32, exp: u32) -> App {
+        let curve = ExperienceCurve::default();
+        let mut app = App::new();
+        app.add_event::<LevelUpEvent>()
+            .insert_resource(PlayerExperience(exp))
+            .init_resource::<PendingLevelUps>()
+            .add_systems(Update, (gain_levels, level_up).chain());
+        app.world.spawn((
+            Player,
+            PlayerLevel {
+                level,
+                next_level_delta: ExpGain(curve.required(level)),
+            },
+        ));
+        app.insert_resource(curve);
+        app
+    }
+
+    fn player_level(app: &mut App) -> (u32, u32) {
+        let level = app.world.query::<&PlayerLevel>().single(&app.world);
+        (level.level, level.next_level_delta.0)
+    }
+
+    fn crumb_totals(app: &mut App) -> (usize, u32) {
+        let gains: Vec<u32> = app
+            .world
+            .query_filtered::<&ExpGain, With<ExpCrumb>>()
+            .iter(&app.world)
+            .map(|gain| gain.0)
+            .collect();
+        (gains.len(), gains.iter().sum())
+    }
+
+    #[test]
+    fn merging_caps_crumbs_and_keeps_their_xp() {
+        let mut app = App::new();
+        app.add_systems(Update, merge_crumbs);
+        app.world.spawn((Player, TransformBundle::default()));
+        app.world.spawn_batch((0..MAX_CRUMBS as u32 + 150).map(|i| {
+            let position = Vec3::new(
+                (i * 37 % 4000) as f32 - 2000.,
+                (i * 91 % 4000) as f32 - 2000.,
+                0.,
+            );
+            ExpCrumbBundle::new(Transform::from_translation(position), ExpGain(i % 7 + 1))
+        }));
+        let (count, total) = crumb_totals(&mut app);
+        assert!(count > MAX_CRUMBS);
+
+        app.update();
+
+        let (merged_count, merged_total) = crumb_totals(&mut app);
+        assert!(merged_count <= MAX_CRUMBS);
+        assert_eq!(merged_total, total);
+    }
+
+    #[test]
+    fn large_gain_queues_every_level_crossed() {
+        let curve = ExperienceCurve::default();
+        let costs: u32 = (1..=4).map(|level| curve.required(level)).sum();
+        let mut app = leveling_app(1, costs + 7);
+        app.update();
+
+        assert_eq!(app.world.resource::<PendingLevelUps>().0, 4);
+        assert_eq!(app.world.resource::<PlayerExperience>().0, 7);
+        assert_eq!(player_level(&mut app), (5, curve.required(5)));
+    }
+
+    #[test]
+    fn overflow_carries_over_after_the_last_threshold() {
+        let curve = ExperienceCurve::default();
+        let total = 1000;
+        let mut app = leveling_app(3, total);
+        app.update();
+
+        let (level, next) = player_level(&mut app);
+        let spent: u32 = (3..level).map(|crossed| curve.required(crossed)).sum();
+        let leftover = app.world.resource::<PlayerExperience>().0;
+        assert!(level > 4);
+        assert_eq!(app.world.resource::<PendingLevelUps>().0, level - 3);
+        assert_eq!(leftover, total - spent);
+        assert!(leftover < next);
+        assert_eq!(next, curve.required(level));
+    }
+}
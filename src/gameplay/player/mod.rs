@@ -1,12 +1,12 @@
 use bevy::prelude::*;
 
-use crate::gameplay::states::GameState;
+use crate::gameplay::{states::GameState, system_sets::RunSet, systems::clear_events};
 
 use self::{
     crumbs::ExpCrumbPlugin,
     events::PlayerMoveEvent,
-    resources::PlayerExperience,
-    systems::{draw_player, move_player},
+    resources::{ExperienceCurve, PendingLevelUps, PlayerExperience},
+    systems::{draw_player, move_player, reset_player_experience, update_shield_ring},
 };
 
 pub mod bundles;
@@ -22,8 +22,20 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ExpCrumbPlugin)
             .insert_resource(PlayerExperience(0))
+            .init_resource::<ExperienceCurve>()
+            .init_resource::<PendingLevelUps>()
             .add_event::<PlayerMoveEvent>()
-            .add_systems(Startup, draw_player)
-            .add_systems(Update, (move_player).run_if(in_state(GameState::Running)));
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (
+                    (reset_player_experience, clear_events::<PlayerMoveEvent>)
+                        .in_set(RunSet::Reset),
+                    (draw_player).in_set(RunSet::Setup),
+                ),
+            )
+            .add_systems(
+                Update,
+                (move_player, update_shield_ring).run_if(in_state(GameState::Running)),
+            );
     }
 }
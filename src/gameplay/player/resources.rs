@@ -2,3 +2,34 @@ use bevy::prelude::*;
 
 #[derive(Resource)]
 pub struct PlayerExperience(pub u32);
+
+/// XP needed to finish a level: `base + growth * (level - 1) ^ exponent`.
+#[derive(Resource)]
+pub struct ExperienceCurve {
+    pub base: f32,
+    pub growth: f32,
+    pub exponent: f32,
+}
+
+impl Default for ExperienceCurve {
+    fn default() -> Self {
+        Self {
+            base: 20.,
+            growth: 20.,
+            exponent: 1.3,
+        }
+    }
+}
+
+impl ExperienceCurve {
+    pub fn required(&self, level: u32) -> u32 {
+        let steps = level.saturating_sub(1) as f32;
+        (self.base + self.growth * steps.powf(self.exponent))
+            .round()
+            .max(1.) as u32
+    }
+}
+
+/// Level-ups gained but not yet spent on the upgrade menu.
+#[derive(Resource, Default)]
+pub struct PendingLevelUps(pub u32);
@@ -1,12 +1,85 @@
 use bevy::prelude::*;
 
 use super::bundles::PlayerBundle;
-use crate::gameplay::{components::Speed, MoveDirection};
+use crate::gameplay::{
+    components::{RemoveOnReset, Shield, Speed},
+    MoveDirection,
+};
 
-use super::{components::Player, events::PlayerMoveEvent};
+use super::{
+    components::{Player, ShieldRing},
+    events::PlayerMoveEvent,
+    resources::{ExperienceCurve, PendingLevelUps, PlayerExperience},
+};
 
-pub fn draw_player(mut commands: Commands) {
-    commands.spawn(PlayerBundle::default());
+const SHIELD_RING_SIZE: f32 = 44.;
+const SHIELD_RING_WIDTH: f32 = 3.;
+const SHIELD_RING_ALPHA: f32 = 0.8;
+
+pub fn draw_player(mut commands: Commands, curve: Res<ExperienceCurve>) {
+    commands
+        .spawn(PlayerBundle::new(&curve))
+        .with_children(|parent| {
+            let offset = (SHIELD_RING_SIZE - SHIELD_RING_WIDTH) / 2.;
+            [
+                (
+                    Vec2::new(0., offset),
+                    Vec2::new(SHIELD_RING_SIZE, SHIELD_RING_WIDTH),
+                ),
+                (
+                    Vec2::new(0., -offset),
+                    Vec2::new(SHIELD_RING_SIZE, SHIELD_RING_WIDTH),
+                ),
+                (
+                    Vec2::new(offset, 0.),
+                    Vec2::new(SHIELD_RING_WIDTH, SHIELD_RING_SIZE),
+                ),
+                (
+                    Vec2::new(-offset, 0.),
+                    Vec2::new(SHIELD_RING_WIDTH, SHIELD_RING_SIZE),
+                ),
+            ]
+            .into_iter()
+            .for_each(|(position, size)| {
+                parent.spawn((
+                    ShieldRing,
+                    RemoveOnReset,
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::CYAN.with_a(0.),
+                            custom_size: Some(size),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(position.extend(0.5)),
+                        ..default()
+                    },
+                ));
+            });
+        });
+}
+
+pub fn update_shield_ring(
+    q_player: Query<&Shield, (With<Player>, Changed<Shield>)>,
+    mut q_ring: Query<&mut Sprite, With<ShieldRing>>,
+) {
+    if let Ok(shield) = q_player.get_single() {
+        let fraction = if shield.max > 0. {
+            shield.current / shield.max
+        } else {
+            0.
+        };
+        q_ring.iter_mut().for_each(|mut sprite| {
+            sprite.color.set_a(SHIELD_RING_ALPHA * fraction);
+        });
+    }
+}
+
+pub fn reset_player_experience(
+    mut exp: ResMut<PlayerExperience>,
+    mut pending: ResMut<PendingLevelUps>,
+) {
+    *exp = PlayerExperience(0);
+    *pending = PendingLevelUps::default();
 }
 
 pub fn move_player(
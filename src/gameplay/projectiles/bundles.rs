@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::gameplay::components::{Damage, MyDirection, RemoveOnReset, Shooter, Speed};
+use crate::gameplay::components::{Critical, Damage, MyDirection, RemoveOnReset, Shooter, Speed};
 
 use super::components::{Bullet, BulletLifetimeTimer};
 
@@ -10,6 +10,7 @@ pub struct BulletBundle {
     pub marker: Bullet,
     pub direction: MyDirection,
     pub damage: Damage,
+    pub critical: Critical,
     pub lifetime: BulletLifetimeTimer,
     pub sprite: SpriteBundle,
     pub shooter: Shooter,
@@ -25,6 +26,7 @@ impl Default for BulletBundle {
             lifetime: BulletLifetimeTimer(Timer::from_seconds(20., TimerMode::Once)),
             shooter: Shooter::Player,
             damage: Damage(5),
+            critical: Critical(false),
             remove_on_reset: RemoveOnReset,
             sprite: SpriteBundle {
                 sprite: Sprite {
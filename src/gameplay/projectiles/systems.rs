@@ -2,13 +2,19 @@ use bevy::{
     math::bounding::{Aabb2d, IntersectsVolume},
     prelude::*,
 };
+use rand::{thread_rng, Rng};
 
 use crate::{
     gameplay::{
-        components::{Damage, Distance, Health, MyDirection, Pushed, Shooter, Speed},
+        components::{
+            Armor, Critical, Damage, DamageReduction, Distance, Health, MyDirection, Pushed,
+            Shield, Shooter, Speed,
+        },
+        enemies::elites::components::ReflectsBullets,
         get_delta, get_direction,
+        systems::apply_damage,
     },
-    ShootEvent,
+    DamageEvent, ShootEvent,
 };
 
 use super::{
@@ -24,6 +30,7 @@ pub fn bullet_spawner(mut commands: Commands, mut ev_shoot: EventReader<ShootEve
              damage,
              shooter,
              bullet_speed,
+             critical,
          }| {
             commands.spawn(BulletBundle {
                 sprite: SpriteBundle {
@@ -37,6 +44,7 @@ pub fn bullet_spawner(mut commands: Commands, mut ev_shoot: EventReader<ShootEve
                 },
                 direction: MyDirection(get_direction(target, source)),
                 damage: damage.clone(),
+                critical: *critical,
                 shooter: *shooter,
                 speed: Speed(bullet_speed.0),
                 ..default()
@@ -45,7 +53,18 @@ pub fn bullet_spawner(mut commands: Commands, mut ev_shoot: EventReader<ShootEve
     )
 }
 pub fn bullet_collision_processing(
-    mut q_bullets: Query<(&Transform, &Sprite, &Shooter, &Damage, Entity), With<Bullet>>,
+    mut q_bullets: Query<
+        (
+            &Transform,
+            &Sprite,
+            &mut Shooter,
+            &mut MyDirection,
+            &Damage,
+            &Critical,
+            Entity,
+        ),
+        With<Bullet>,
+    >,
     mut q_colliders: Query<
         (
             &Transform,
@@ -53,15 +72,30 @@ pub fn bullet_collision_processing(
             &mut Health,
             &Shooter,
             Option<&Pushed>,
+            Option<&Armor>,
+            Option<&DamageReduction>,
+            Option<&mut Shield>,
+            Option<&ReflectsBullets>,
             Entity,
         ),
         Without<Bullet>,
     >,
+    mut ev_damage: EventWriter<DamageEvent>,
     mut commands: Commands,
 ) {
     q_bullets.iter_mut().for_each(
-        |(bullet_tr, bullet_sprite, bullet_shooter, bullet_dmg, bullet_entity)| {
+        |(
+            bullet_tr,
+            bullet_sprite,
+            mut bullet_shooter,
+            mut bullet_dir,
+            bullet_dmg,
+            bullet_crit,
+            bullet_entity,
+        )| {
             let bullet_size = bullet_sprite.custom_size.unwrap();
+            let mut rng = thread_rng();
+            let mut reflected = false;
 
             q_colliders.iter_mut().for_each(
                 |(
@@ -70,16 +104,44 @@ pub fn bullet_collision_processing(
                     mut collider_hp,
                     entity_shooter,
                     collider_is_pushed,
+                    collider_armor,
+                    collider_reduction,
+                    mut collider_shield,
+                    collider_reflects,
                     collider_entity,
                 )| {
+                    if reflected {
+                        return;
+                    }
                     let collider_size = collider_sprite.custom_size.unwrap();
                     if Aabb2d::new(collider_tr.translation.xy(), collider_size * 0.5)
                         .intersects(&Aabb2d::new(bullet_tr.translation.xy(), bullet_size * 0.5))
                     {
-                        match (bullet_shooter, entity_shooter) {
+                        match (*bullet_shooter, entity_shooter) {
+                            (Shooter::Player, Shooter::Enemy)
+                                if collider_reflects
+                                    .is_some_and(|reflects| rng.gen_bool(reflects.chance)) =>
+                            {
+                                *bullet_shooter = Shooter::Enemy;
+                                bullet_dir.0 = -bullet_dir.0;
+                                reflected = true;
+                            }
                             (Shooter::Enemy, Shooter::Player)
                             | (Shooter::Player, Shooter::Enemy) => {
-                                collider_hp.0 -= bullet_dmg.0;
+                                apply_damage(
+                                    DamageEvent {
+                                        target: collider_entity,
+                                        position: collider_tr.translation.xy(),
+                                        amount: bullet_dmg.0,
+                                        dealt_by: *bullet_shooter,
+                                        critical: *bullet_crit,
+                                    },
+                                    &mut collider_hp,
+                                    collider_armor,
+                                    collider_reduction,
+                                    collider_shield.as_deref_mut(),
+                                    &mut ev_damage,
+                                );
                                 if collider_is_pushed.is_none() {
                                     commands.entity(collider_entity).insert(Pushed {
                                         distance: Distance(25.),
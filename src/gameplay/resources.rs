@@ -0,0 +1,21 @@
+use bevy::{prelude::*, time::Stopwatch};
+
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    pub trauma: f32,
+}
+
+/// Time spent in the current run, not counting pauses or menus.
+#[derive(Resource, Default)]
+pub struct RunClock(pub Stopwatch);
+
+/// Rolled fresh for every run; procedural content derives from it so a run's world
+/// can be rebuilt identically.
+#[derive(Resource)]
+pub struct RunSeed(pub u64);
+
+impl Default for RunSeed {
+    fn default() -> Self {
+        Self(rand::random())
+    }
+}
@@ -3,6 +3,10 @@ use bevy::prelude::*;
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GameState {
     #[default]
+    MainMenu,
+    Settings,
+    Stats,
+    Starting,
     Running,
     Paused,
     Upgrading,
@@ -0,0 +1,289 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::Deserialize;
+
+use crate::gameplay::{pickups::components::PickupKind, upgrades::assets::UpgradeId};
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stat {
+    Speed,
+    Damage,
+    Health,
+    ReloadTime,
+    ShotSpeed,
+    CritChance,
+    CollectRadius,
+    Armor,
+    Regen,
+    Luck,
+    CooldownReduction,
+    Shield,
+    DamageReduction,
+}
+
+impl Stat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Stat::Speed => "Speed",
+            Stat::Damage => "Damage",
+            Stat::Health => "Max health",
+            Stat::ReloadTime => "Reload",
+            Stat::ShotSpeed => "Shot speed",
+            Stat::CritChance => "Crit",
+            Stat::CollectRadius => "Magnet",
+            Stat::Armor => "Armor",
+            Stat::Regen => "Regen",
+            Stat::Luck => "Luck",
+            Stat::CooldownReduction => "Cooldown",
+            Stat::Shield => "Shield",
+            Stat::DamageReduction => "Resist",
+        }
+    }
+
+    pub fn format(&self, value: f32) -> String {
+        match self {
+            Stat::ReloadTime => format!("{:.2}s", value),
+            Stat::CritChance | Stat::Luck | Stat::CooldownReduction | Stat::DamageReduction => {
+                format!("{:.0}%", value * 100.)
+            }
+            Stat::Regen => format!("{:.1}/s", value),
+            _ => format!("{:.0}", value),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModifierKind {
+    Flat,
+    PercentAdd,
+    Multiply,
+}
+
+// Nothing applies effect or pickup modifiers yet; these variants and the timed
+// helpers below are there for the first ones that do.
+
+/// Temporary conditions on an entity that adjust its stats while they last.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusEffect {
+    Stunned,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModifierSource {
+    Upgrade(UpgradeId),
+    Effect(StatusEffect),
+    Pickup(PickupKind),
+}
+
+#[derive(Clone, Debug)]
+pub struct StatModifier {
+    pub stat: Stat,
+    pub kind: ModifierKind,
+    pub value: f32,
+    pub source: ModifierSource,
+    pub duration: Option<Timer>,
+}
+
+impl StatModifier {
+    pub fn permanent(stat: Stat, kind: ModifierKind, value: f32, source: ModifierSource) -> Self {
+        Self {
+            stat,
+            kind,
+            value,
+            source,
+            duration: None,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn timed(
+        stat: Stat,
+        kind: ModifierKind,
+        value: f32,
+        source: ModifierSource,
+        seconds: f32,
+    ) -> Self {
+        Self {
+            duration: Some(Timer::from_seconds(seconds, TimerMode::Once)),
+            ..Self::permanent(stat, kind, value, source)
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct Stats {
+    base: HashMap<Stat, f32>,
+    modifiers: Vec<StatModifier>,
+}
+
+impl Stats {
+    pub fn new(base: impl IntoIterator<Item = (Stat, f32)>) -> Self {
+        Self {
+            base: base.into_iter().collect(),
+            modifiers: Vec::new(),
+        }
+    }
+
+    pub fn has(&self, stat: Stat) -> bool {
+        self.base.contains_key(&stat)
+    }
+
+    pub fn base(&self, stat: Stat) -> f32 {
+        self.base.get(&stat).copied().unwrap_or(0.)
+    }
+
+    pub fn value(&self, stat: Stat) -> f32 {
+        let (flat, percent, multiplier) = self
+            .modifiers
+            .iter()
+            .filter(|modifier| modifier.stat == stat)
+            .fold(
+                (0., 0., 1.),
+                |(flat, percent, multiplier), modifier| match modifier.kind {
+                    ModifierKind::Flat => (flat + modifier.value, percent, multiplier),
+                    ModifierKind::PercentAdd => (flat, percent + modifier.value, multiplier),
+                    ModifierKind::Multiply => (flat, percent, multiplier * modifier.value),
+                },
+            );
+        (self.base(stat) + flat) * (1. + percent) * multiplier
+    }
+
+    /// Timed modifiers refresh when reapplied from the same source instead of stacking.
+    pub fn add_modifier(&mut self, modifier: StatModifier) {
+        if modifier.duration.is_some() {
+            self.modifiers.retain(|existing| {
+                existing.duration.is_none()
+                    || existing.stat != modifier.stat
+                    || existing.source != modifier.source
+            });
+        }
+        self.modifiers.push(modifier);
+    }
+
+    /// Drops every modifier pushed by `source`. Returns whether any were removed.
+    #[allow(dead_code)]
+    pub fn remove_from(&mut self, source: &ModifierSource) -> bool {
+        let before = self.modifiers.len();
+        self.modifiers.retain(|modifier| &modifier.source != source);
+        before != self.modifiers.len()
+    }
+
+    pub fn has_timed_modifiers(&self) -> bool {
+        self.modifiers
+            .iter()
+            .any(|modifier| modifier.duration.is_some())
+    }
+
+    /// Ticks timed modifiers and drops the expired ones. Returns whether any were removed.
+    pub fn tick(&mut self, delta: std::time::Duration) -> bool {
+        let before = self.modifiers.len();
+        self.modifiers
+            .retain_mut(|modifier| match &mut modifier.duration {
+                Some(timer) => !timer.tick(delta).finished(),
+                None => true,
+            });
+        before != self.modifiers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn upgrade(id: &str) -> ModifierSource {
+        ModifierSource::Upgrade(UpgradeId(id.to_string()))
+    }
+
+    #[test]
+    fn value_layers_flat_then_percent_then_multiply() {
+        let mut stats = Stats::new([(Stat::Damage, 10.)]);
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Damage,
+            ModifierKind::Multiply,
+            2.,
+            upgrade("a"),
+        ));
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Damage,
+            ModifierKind::PercentAdd,
+            0.25,
+            upgrade("b"),
+        ));
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Damage,
+            ModifierKind::Flat,
+            5.,
+            upgrade("c"),
+        ));
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Damage,
+            ModifierKind::PercentAdd,
+            0.25,
+            upgrade("d"),
+        ));
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Speed,
+            ModifierKind::Flat,
+            100.,
+            upgrade("e"),
+        ));
+
+        assert_eq!(stats.base(Stat::Damage), 10.);
+        // (10 + 5) * (1 + 0.25 + 0.25) * 2
+        assert_eq!(stats.value(Stat::Damage), 45.);
+    }
+
+    #[test]
+    fn timed_modifiers_expire_on_tick() {
+        let mut stats = Stats::new([(Stat::Speed, 100.)]);
+        let pickup = ModifierSource::Pickup(PickupKind::Freeze);
+        stats.add_modifier(StatModifier::timed(
+            Stat::Speed,
+            ModifierKind::PercentAdd,
+            0.5,
+            pickup.clone(),
+            2.,
+        ));
+        // Reapplying refreshes instead of stacking
+        stats.add_modifier(StatModifier::timed(
+            Stat::Speed,
+            ModifierKind::PercentAdd,
+            0.5,
+            pickup,
+            2.,
+        ));
+        assert_eq!(stats.value(Stat::Speed), 150.);
+
+        assert!(!stats.tick(Duration::from_secs_f32(1.5)));
+        assert_eq!(stats.value(Stat::Speed), 150.);
+        assert!(stats.tick(Duration::from_secs_f32(1.)));
+        assert_eq!(stats.value(Stat::Speed), 100.);
+        assert!(!stats.has_timed_modifiers());
+    }
+
+    #[test]
+    fn remove_from_only_drops_that_source() {
+        let mut stats = Stats::new([(Stat::Speed, 100.)]);
+        let stunned = ModifierSource::Effect(StatusEffect::Stunned);
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Speed,
+            ModifierKind::PercentAdd,
+            0.2,
+            stunned.clone(),
+        ));
+        stats.add_modifier(StatModifier::permanent(
+            Stat::Speed,
+            ModifierKind::Flat,
+            10.,
+            upgrade("speed"),
+        ));
+        assert_eq!(stats.value(Stat::Speed), 132.);
+
+        assert!(stats.remove_from(&stunned));
+        assert_eq!(stats.value(Stat::Speed), 110.);
+        assert!(!stats.remove_from(&stunned));
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+use self::systems::{sync_stats, tick_stat_modifiers};
+
+pub mod components;
+pub mod systems;
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (tick_stat_modifiers).run_if(in_state(GameState::Running)),
+                sync_stats,
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::gameplay::{
+    components::{
+        Armor, CooldownReduction, CritChance, Damage, DamageReduction, Health, Luck, MaxHealth,
+        Regen, ReloadTime, Shield, ShotSpeed, Speed,
+    },
+    player::crumbs::components::CrumbCollectRadius,
+};
+
+use super::components::{Stat, Stats};
+
+pub fn tick_stat_modifiers(mut q_stats: Query<&mut Stats>, time: Res<Time>) {
+    q_stats.iter_mut().for_each(|mut stats| {
+        if stats.has_timed_modifiers() && stats.bypass_change_detection().tick(time.delta()) {
+            stats.set_changed();
+        }
+    });
+}
+
+pub fn sync_stats(
+    mut q_stats: Query<
+        (
+            &Stats,
+            Option<&mut MaxHealth>,
+            Option<&mut Health>,
+            Option<&mut Speed>,
+            Option<&mut Damage>,
+            Option<&mut ReloadTime>,
+            Option<&mut ShotSpeed>,
+            Option<&mut CritChance>,
+            Option<&mut CrumbCollectRadius>,
+            Option<&mut Armor>,
+            Option<&mut Regen>,
+            Option<&mut Luck>,
+            Option<&mut CooldownReduction>,
+            Option<&mut Shield>,
+            Option<&mut DamageReduction>,
+        ),
+        Changed<Stats>,
+    >,
+) {
+    q_stats.iter_mut().for_each(
+        |(
+            stats,
+            max_health,
+            health,
+            speed,
+            damage,
+            reload_time,
+            shot_speed,
+            crit_chance,
+            collect_radius,
+            armor,
+            regen,
+            luck,
+            cooldown_reduction,
+            shield,
+            damage_reduction,
+        )| {
+            if let (Some(mut max_health), true) = (max_health, stats.has(Stat::Health)) {
+                let max = (stats.value(Stat::Health).round() as i32).max(1);
+                if let Some(mut health) = health {
+                    // Raising max health also grants the extra points
+                    let gained = (max - max_health.0).max(0);
+                    health.0 = (health.0 + gained).min(max);
+                }
+                max_health.0 = max;
+            }
+            if let (Some(mut speed), true) = (speed, stats.has(Stat::Speed)) {
+                speed.0 = stats.value(Stat::Speed);
+            }
+            if let (Some(mut damage), true) = (damage, stats.has(Stat::Damage)) {
+                damage.0 = stats.value(Stat::Damage).round() as i32;
+            }
+            if let (Some(mut reload_time), true) = (reload_time, stats.has(Stat::ReloadTime)) {
+                reload_time.0 = Duration::from_secs_f32(stats.value(Stat::ReloadTime).max(0.01));
+            }
+            if let (Some(mut shot_speed), true) = (shot_speed, stats.has(Stat::ShotSpeed)) {
+                shot_speed.0 = stats.value(Stat::ShotSpeed);
+            }
+            if let (Some(mut crit_chance), true) = (crit_chance, stats.has(Stat::CritChance)) {
+                crit_chance.0 = stats.value(Stat::CritChance).clamp(0., 1.);
+            }
+            if let (Some(mut collect_radius), true) =
+                (collect_radius, stats.has(Stat::CollectRadius))
+            {
+                collect_radius.0 = stats.value(Stat::CollectRadius);
+            }
+            if let (Some(mut armor), true) = (armor, stats.has(Stat::Armor)) {
+                armor.0 = stats.value(Stat::Armor).max(0.);
+            }
+            if let (Some(mut regen), true) = (regen, stats.has(Stat::Regen)) {
+                regen.rate = stats.value(Stat::Regen).max(0.);
+            }
+            if let (Some(mut luck), true) = (luck, stats.has(Stat::Luck)) {
+                luck.0 = stats.value(Stat::Luck).max(0.);
+            }
+            if let (Some(mut cooldown_reduction), true) =
+                (cooldown_reduction, stats.has(Stat::CooldownReduction))
+            {
+                cooldown_reduction.0 = stats.value(Stat::CooldownReduction).clamp(0., 0.75);
+            }
+            if let (Some(mut shield), true) = (shield, stats.has(Stat::Shield)) {
+                shield.max = stats.value(Stat::Shield).max(0.);
+                shield.current = shield.current.min(shield.max);
+            }
+            if let (Some(mut damage_reduction), true) =
+                (damage_reduction, stats.has(Stat::DamageReduction))
+            {
+                damage_reduction.0 = stats.value(Stat::DamageReduction).clamp(0., 0.75);
+            }
+        },
+    );
+}
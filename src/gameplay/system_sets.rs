@@ -8,8 +8,19 @@ pub enum GameplaySet {
     Global,
 }
 
+/// Systems reacting to enemies marked `Dead` before `dead_cleanup` despawns them.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeathHooks;
+
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum InputSet {
     Mouse,
     Keyboard,
 }
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RunSet {
+    Cleanup,
+    Reset,
+    Setup,
+}
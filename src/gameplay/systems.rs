@@ -1,22 +1,59 @@
 use crate::events::*;
 use crate::gameplay::player::components::Player;
 use crate::gameplay::player::events::PlayerMoveEvent;
+use crate::settings::resources::Settings;
 
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
+use rand::Rng;
 
 use super::bundles::MainCameraBundle;
 use super::components::{
-    Damage, Dead, Health, HitBlinkTimer, Invulnerable, MainCamera, Pushed, ReloadStopwatch,
-    ReloadTime, Shooter, ShotSpeed,
+    Armor, CritChance, Critical, Damage, DamageReduction, Dead, Health, HitBlinkTimer,
+    Invulnerable, MainCamera, MaxHealth, Pushed, Regen, ReloadStopwatch, ReloadTime, RemoveOnReset,
+    Shield, Shooter, ShotSpeed,
 };
 use super::get_delta;
+use super::resources::{RunClock, RunSeed, ScreenShake};
 use super::states::GameState;
 use super::MoveDirection;
 
+const SHIELD_RECHARGE_DELAY: f32 = 3.;
+/// Share of the full shield restored per second while recharging.
+const SHIELD_RECHARGE_RATE: f32 = 0.5;
+
 pub fn draw_camera(mut commands: Commands) {
     commands.spawn(MainCameraBundle::default());
 }
 
+pub fn despawn_run_entities(q_remove: Query<Entity, With<RemoveOnReset>>, mut commands: Commands) {
+    q_remove.iter().for_each(|entity| {
+        commands.entity(entity).despawn_recursive();
+    });
+}
+
+pub fn begin_run(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Running);
+}
+
+pub fn request_new_run(
+    mut ev_new_run: EventReader<NewRunEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if ev_new_run.read().count() > 0 {
+        next_state.set(GameState::Starting);
+    }
+}
+
+pub fn clear_events<T: Event>(mut events: ResMut<Events<T>>) {
+    events.clear();
+}
+
+pub fn reset_camera(mut q_camera: Query<&mut Transform, With<MainCamera>>) {
+    if let Ok(mut camera_tr) = q_camera.get_single_mut() {
+        *camera_tr = MainCameraBundle::default().camera.transform;
+    }
+}
+
 pub fn invulnerable_tick(
     mut q_invulnerable: Query<(&mut Invulnerable, &mut Visibility, Entity)>,
     mut commands: Commands,
@@ -60,6 +97,56 @@ pub fn fix_camera_to_player(
     }
 }
 
+pub fn add_screen_shake(
+    mut ev_damage: EventReader<DamageEvent>,
+    q_player: Query<Entity, With<Player>>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    if let Ok(player) = q_player.get_single() {
+        ev_damage
+            .read()
+            .filter(|ev| ev.target == player)
+            .for_each(|_| shake.trauma = (shake.trauma + 0.5).min(1.));
+    }
+}
+
+pub fn shake_camera(
+    mut q_camera: Query<&mut Transform, With<MainCamera>>,
+    mut shake: ResMut<ScreenShake>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    if shake.trauma <= 0. {
+        return;
+    }
+    if let Ok(mut camera_tr) = q_camera.get_single_mut() {
+        let mut rng = rand::thread_rng();
+        let strength = 12. * settings.screen_shake * shake.trauma.powi(2);
+        camera_tr.translation += Vec3::new(
+            rng.gen_range(-1. ..1.) * strength,
+            rng.gen_range(-1. ..1.) * strength,
+            0.,
+        );
+    }
+    shake.trauma = (shake.trauma - 1.5 * time.delta_seconds()).max(0.);
+}
+
+pub fn reset_screen_shake(mut shake: ResMut<ScreenShake>) {
+    *shake = ScreenShake::default();
+}
+
+pub fn tick_run_clock(mut clock: ResMut<RunClock>, time: Res<Time>) {
+    clock.0.tick(time.delta());
+}
+
+pub fn reset_run_clock(mut clock: ResMut<RunClock>) {
+    *clock = RunClock::default();
+}
+
+pub fn reset_run_seed(mut seed: ResMut<RunSeed>) {
+    *seed = RunSeed::default();
+}
+
 pub fn push_processor(
     mut q_pushed: Query<(&mut Transform, &mut Pushed, Entity)>,
     mut commands: Commands,
@@ -97,46 +184,109 @@ pub fn dead_mark(
         });
 }
 
-pub fn dead_cleanup(q_dead: Query<Entity, With<Dead>>, mut commands: Commands) {
+// The player stays on screen behind the game over menu until the next run
+pub fn dead_cleanup(q_dead: Query<Entity, (With<Dead>, Without<Player>)>, mut commands: Commands) {
     q_dead
         .iter()
-        .for_each(|dead| commands.entity(dead).despawn());
+        .for_each(|dead| commands.entity(dead).despawn_recursive());
 }
 
 pub fn on_hit_highlight(
+    mut ev_damage: EventReader<DamageEvent>,
     mut hit_query: Query<
-        (
-            &mut Sprite,
-            Ref<Health>,
-            Option<&Player>,
-            Option<&Invulnerable>,
-            Entity,
-        ),
-        (Changed<Health>, Without<Dead>),
+        (&mut Sprite, Option<&Player>, Option<&Invulnerable>),
+        (Without<Dead>, Without<HitBlinkTimer>),
     >,
     mut commands: Commands,
 ) {
-    hit_query.iter_mut().for_each(
-        |(mut sprite, health, opt_player, opt_invulnerable, entity)| {
-            if health.is_changed() && !sprite.is_changed() {
-                commands.entity(entity).insert(HitBlinkTimer {
-                    return_to: sprite.color,
-                    timer: Timer::from_seconds(0.05, TimerMode::Once),
-                });
-                sprite.color = Color::RED;
-
-                match (opt_player, opt_invulnerable) {
-                    (Some(_), None) => {
-                        commands.entity(entity).insert(Invulnerable {
-                            blink_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
-                            invuln_timer: Timer::from_seconds(2., TimerMode::Once),
-                        });
-                    }
-                    (_, _) => {}
+    let targets: HashSet<Entity> = ev_damage.read().map(|ev| ev.target).collect();
+    targets.into_iter().for_each(|entity| {
+        if let Ok((mut sprite, opt_player, opt_invulnerable)) = hit_query.get_mut(entity) {
+            commands.entity(entity).insert(HitBlinkTimer {
+                return_to: sprite.color,
+                timer: Timer::from_seconds(0.05, TimerMode::Once),
+            });
+            sprite.color = Color::RED;
+
+            match (opt_player, opt_invulnerable) {
+                (Some(_), None) => {
+                    commands.entity(entity).insert(Invulnerable {
+                        blink_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+                        invuln_timer: Timer::from_seconds(2., TimerMode::Once),
+                    });
                 }
+                (_, _) => {}
             }
-        },
-    );
+        }
+    });
+}
+
+pub fn regenerate_health(
+    mut q_regen: Query<(&mut Health, &MaxHealth, &mut Regen)>,
+    time: Res<Time>,
+) {
+    q_regen
+        .iter_mut()
+        .for_each(|(mut health, max_health, mut regen)| {
+            if regen.rate <= 0. || health.0 <= 0 {
+                return;
+            }
+            if health.0 >= max_health.0 {
+                regen.progress = 0.;
+                return;
+            }
+            regen.progress += regen.rate * time.delta_seconds();
+            let healed = regen.progress.floor();
+            if healed >= 1. {
+                regen.progress -= healed;
+                health.heal(healed as i32, max_health);
+            }
+        });
+}
+
+/// Percentage reduction, then flat armor for incoming damage; a hit always deals at least 1.
+fn mitigate(amount: i32, armor: Option<&Armor>, reduction: Option<&DamageReduction>) -> i32 {
+    let reduced = match reduction {
+        Some(reduction) => (amount as f32 * (1. - reduction.0)).round() as i32,
+        None => amount,
+    };
+    match armor {
+        Some(armor) => (reduced - armor.0.round() as i32).max(1),
+        None => reduced.max(1),
+    }
+}
+
+/// Mitigates `hit.amount`, lets the shield soak what it can from the rest and sends the hit.
+pub fn apply_damage(
+    mut hit: DamageEvent,
+    health: &mut Health,
+    armor: Option<&Armor>,
+    reduction: Option<&DamageReduction>,
+    shield: Option<&mut Shield>,
+    ev_damage: &mut EventWriter<DamageEvent>,
+) {
+    hit.amount = mitigate(hit.amount, armor, reduction);
+    health.0 -= match shield {
+        Some(shield) => shield.absorb(hit.amount),
+        None => hit.amount,
+    };
+    ev_damage.send(hit);
+}
+
+pub fn recharge_shields(mut q_shields: Query<&mut Shield>, time: Res<Time>) {
+    // Only a change to `current` marks the shield changed, so its ring isn't rebuilt every frame
+    q_shields.iter_mut().for_each(|mut shield| {
+        if shield.current >= shield.max {
+            return;
+        }
+        if shield.since_hit < SHIELD_RECHARGE_DELAY {
+            shield.bypass_change_detection().since_hit += time.delta_seconds();
+            return;
+        }
+        shield.current = (shield.current
+            + shield.max * SHIELD_RECHARGE_RATE * time.delta_seconds())
+        .min(shield.max);
+    });
 }
 
 pub fn stop_highlight(
@@ -165,10 +315,11 @@ pub fn keyboard_input(
         KeyCode::Escape => match pause_state.get() {
             GameState::Running => next_pause_state.set(GameState::Paused),
             GameState::Paused => next_pause_state.set(GameState::Running),
-            GameState::GameOver => {
+            GameState::GameOver | GameState::MainMenu => {
                 app_exit_events.send(bevy::app::AppExit);
             }
-            GameState::Upgrading => {}
+            GameState::Settings | GameState::Stats | GameState::Upgrading | GameState::Starting => {
+            }
         },
         _ => {}
     });
@@ -197,6 +348,7 @@ pub fn mouse_input(
             &ReloadTime,
             &Damage,
             &ShotSpeed,
+            &CritChance,
         ),
         With<Player>,
     >,
@@ -205,9 +357,17 @@ pub fn mouse_input(
     time: Res<Time>,
     mut ev_shoot: EventWriter<ShootEvent>,
     clicks: Res<ButtonInput<MouseButton>>,
+    settings: Res<Settings>,
 ) {
     if let (
-        Ok((player_tr, mut reload_watch, reload_time, player_dmg, player_shot_speed)),
+        Ok((
+            player_tr,
+            mut reload_watch,
+            reload_time,
+            player_dmg,
+            player_shot_speed,
+            player_crit_chance,
+        )),
         Ok(primary_window),
         Ok((camera, camera_transform)),
     ) = (
@@ -215,26 +375,34 @@ pub fn mouse_input(
         q_windows.get_single(),
         q_camera.get_single(),
     ) {
-        clicks.get_pressed().for_each(|k| match k {
-            MouseButton::Left => {
-                if let Some(world_position) = primary_window
-                    .cursor_position()
-                    .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
-                    .map(|ray| ray.origin.truncate())
-                {
-                    if reload_watch.0.tick(time.delta()).elapsed() >= reload_time.0 {
-                        reload_watch.0.reset();
-                        ev_shoot.send(ShootEvent {
-                            source: player_tr.translation.xy(),
-                            target: world_position,
-                            damage: player_dmg.clone(),
-                            shooter: Shooter::Player,
-                            bullet_speed: player_shot_speed.clone(),
-                        });
-                    }
-                }
+        if !settings.auto_fire && !clicks.pressed(MouseButton::Left) {
+            return;
+        }
+        if let Some(world_position) = primary_window
+            .cursor_position()
+            .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+            .map(|ray| ray.origin.truncate())
+        {
+            if reload_watch.0.tick(time.delta()).elapsed() >= reload_time.0 {
+                reload_watch.0.reset();
+                let (damage, critical) = roll_crit(player_dmg, player_crit_chance);
+                ev_shoot.send(ShootEvent {
+                    source: player_tr.translation.xy(),
+                    target: world_position,
+                    damage,
+                    shooter: Shooter::Player,
+                    bullet_speed: player_shot_speed.clone(),
+                    critical,
+                });
             }
-            _ => {}
-        })
+        }
+    }
+}
+
+pub fn roll_crit(damage: &Damage, crit_chance: &CritChance) -> (Damage, Critical) {
+    if rand::thread_rng().gen_bool(crit_chance.0.clamp(0., 1.) as f64) {
+        (Damage(damage.0 * 2), Critical(true))
+    } else {
+        (damage.clone(), Critical(false))
     }
 }
@@ -0,0 +1,303 @@
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::{BoxedFuture, HashMap, HashSet},
+};
+use rand::{seq::SliceRandom, Rng};
+use serde::Deserialize;
+
+use crate::gameplay::{
+    stats::components::{ModifierKind, Stat},
+    weapons::{components::WeaponKind, MAX_WEAPONS},
+};
+
+use super::MAX_PASSIVES;
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct UpgradeId(pub String);
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+}
+
+impl Rarity {
+    /// Roll weight; luck makes everything above common proportionally more likely.
+    pub fn weight(&self, luck: f32) -> f64 {
+        let boost = 1. + luck.max(0.) as f64;
+        match self {
+            Rarity::Common => 100.,
+            Rarity::Uncommon => 50. * boost,
+            Rarity::Rare => 20. * boost,
+            Rarity::Epic => 5. * boost,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StatModifierDef {
+    pub stat: Stat,
+    pub kind: ModifierKind,
+    pub value: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct UpgradeDefinition {
+    pub id: UpgradeId,
+    pub name: String,
+    pub description: String,
+    pub icon_color: (f32, f32, f32),
+    pub rarity: Rarity,
+    pub max_stacks: u32,
+    /// Passive items take one of the limited item slots and show up in the inventory row.
+    #[serde(default)]
+    pub passive: bool,
+    #[serde(default)]
+    pub prerequisites: Vec<UpgradeId>,
+    #[serde(default)]
+    pub modifiers: Vec<StatModifierDef>,
+    /// Grants this weapon on the first stack and levels it up on every stack after.
+    #[serde(default)]
+    pub weapon: Option<WeaponKind>,
+}
+
+impl UpgradeDefinition {
+    pub fn icon_color(&self) -> Color {
+        let (r, g, b) = self.icon_color;
+        Color::rgb(r, g, b)
+    }
+
+    pub fn is_available(&self, stacks: &HashMap<UpgradeId, u32>) -> bool {
+        stacks.get(&self.id).copied().unwrap_or(0) < self.max_stacks
+            && self
+                .prerequisites
+                .iter()
+                .all(|prereq| stacks.get(prereq).copied().unwrap_or(0) > 0)
+    }
+}
+
+/// Turns a max-level weapon into `result` once the player also owns `passive`.
+#[derive(Deserialize, Clone, Debug)]
+pub struct EvolutionRecipe {
+    pub weapon: UpgradeId,
+    pub passive: UpgradeId,
+    pub result: UpgradeId,
+}
+
+#[derive(Asset, TypePath, Deserialize, Debug)]
+pub struct UpgradeRegistry {
+    pub upgrades: Vec<UpgradeDefinition>,
+    #[serde(default)]
+    pub evolutions: Vec<EvolutionRecipe>,
+}
+
+impl UpgradeRegistry {
+    pub fn get(&self, id: &UpgradeId) -> Option<&UpgradeDefinition> {
+        self.upgrades.iter().find(|upgrade| upgrade.id == *id)
+    }
+
+    pub fn evolution_for(&self, result: &UpgradeId) -> Option<&EvolutionRecipe> {
+        self.evolutions
+            .iter()
+            .find(|recipe| recipe.result == *result)
+    }
+
+    /// Evolutions whose weapon is at max level and whose passive is owned.
+    pub fn ready_evolutions(&self, stacks: &HashMap<UpgradeId, u32>) -> Vec<&UpgradeDefinition> {
+        let count = |id: &UpgradeId| stacks.get(id).copied().unwrap_or(0);
+        self.evolutions
+            .iter()
+            .filter(|recipe| {
+                self.get(&recipe.weapon)
+                    .is_some_and(|weapon| count(&recipe.weapon) >= weapon.max_stacks)
+                    && count(&recipe.passive) > 0
+            })
+            .filter_map(|recipe| self.get(&recipe.result))
+            .filter(|result| result.is_available(stacks))
+            .collect()
+    }
+
+    /// Rolls `amount` upgrades, always offering ready evolutions first.
+    pub fn roll<R: Rng + ?Sized>(
+        &self,
+        stacks: &HashMap<UpgradeId, u32>,
+        excluded: &HashSet<UpgradeId>,
+        amount: usize,
+        luck: f32,
+        rng: &mut R,
+    ) -> Vec<&UpgradeDefinition> {
+        let owned = |upgrade: &UpgradeDefinition| stacks.get(&upgrade.id).copied().unwrap_or(0) > 0;
+        let slots_full = |in_slot: fn(&UpgradeDefinition) -> bool, max: usize| {
+            self.upgrades
+                .iter()
+                .filter(|upgrade| {
+                    in_slot(upgrade) && owned(upgrade) && self.evolution_for(&upgrade.id).is_none()
+                })
+                .count()
+                >= max
+        };
+        let weapon_slots_full = slots_full(|upgrade| upgrade.weapon.is_some(), MAX_WEAPONS);
+        let passive_slots_full = slots_full(|upgrade| upgrade.passive, MAX_PASSIVES);
+        let mut chosen: Vec<&UpgradeDefinition> = self
+            .ready_evolutions(stacks)
+            .into_iter()
+            .filter(|evolution| !excluded.contains(&evolution.id))
+            .take(amount)
+            .collect();
+        let available: Vec<&UpgradeDefinition> = self
+            .upgrades
+            .iter()
+            .filter(|upgrade| upgrade.is_available(stacks) && !excluded.contains(&upgrade.id))
+            .filter(|upgrade| upgrade.weapon.is_none() || owned(upgrade) || !weapon_slots_full)
+            .filter(|upgrade| !upgrade.passive || owned(upgrade) || !passive_slots_full)
+            .filter(|upgrade| self.evolution_for(&upgrade.id).is_none())
+            .collect();
+        chosen.extend(
+            available
+                .choose_multiple_weighted(rng, amount - chosen.len(), |upgrade| {
+                    upgrade.rarity.weight(luck)
+                })
+                .map(|rolled| rolled.copied().collect::<Vec<_>>())
+                .unwrap_or_default(),
+        );
+        chosen
+    }
+}
+
+#[derive(Default)]
+pub struct UpgradeRegistryLoader;
+
+impl AssetLoader for UpgradeRegistryLoader {
+    type Asset = UpgradeRegistry;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(ron::de::from_bytes::<UpgradeRegistry>(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["upgrades.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    const REGISTRY: &str = r#"(
+        upgrades: [
+            (id: "blades", name: "Blades", description: "", icon_color: (1., 1., 1.),
+                rarity: Common, max_stacks: 2, weapon: Some(OrbitingBlades)),
+            (id: "speed", name: "Speed", description: "", icon_color: (1., 1., 1.),
+                rarity: Common, max_stacks: 5, passive: true,
+                modifiers: [(stat: Speed, kind: PercentAdd, value: 0.1)]),
+            (id: "damage", name: "Damage", description: "", icon_color: (1., 1., 1.),
+                rarity: Common, max_stacks: 5,
+                modifiers: [(stat: Damage, kind: Flat, value: 5.)]),
+            (id: "blade_storm", name: "Blade storm", description: "", icon_color: (1., 1., 1.),
+                rarity: Epic, max_stacks: 1, weapon: Some(BladeStorm)),
+        ],
+        evolutions: [
+            (weapon: "blades", passive: "speed", result: "blade_storm"),
+        ],
+    )"#;
+
+    fn registry() -> UpgradeRegistry {
+        ron::de::from_str(REGISTRY).unwrap()
+    }
+
+    fn stacks(entries: &[(&str, u32)]) -> HashMap<UpgradeId, u32> {
+        entries
+            .iter()
+            .map(|(id, count)| (UpgradeId(id.to_string()), *count))
+            .collect()
+    }
+
+    fn rolled_ids(registry: &UpgradeRegistry, stacks: &HashMap<UpgradeId, u32>) -> Vec<String> {
+        registry
+            .roll(
+                stacks,
+                &HashSet::new(),
+                3,
+                0.,
+                &mut StdRng::seed_from_u64(7),
+            )
+            .into_iter()
+            .map(|upgrade| upgrade.id.0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn evolution_walks_through_recipe() {
+        let registry = registry();
+        let evolution = UpgradeId("blade_storm".to_string());
+
+        let fresh = stacks(&[]);
+        assert!(registry.ready_evolutions(&fresh).is_empty());
+        assert!(!rolled_ids(&registry, &fresh).contains(&evolution.0));
+
+        let below_max = stacks(&[("blades", 1), ("speed", 1)]);
+        assert!(registry.ready_evolutions(&below_max).is_empty());
+        assert!(!rolled_ids(&registry, &below_max).contains(&evolution.0));
+
+        let missing_passive = stacks(&[("blades", 2)]);
+        assert!(registry.ready_evolutions(&missing_passive).is_empty());
+        assert!(!rolled_ids(&registry, &missing_passive).contains(&evolution.0));
+
+        let ready = stacks(&[("blades", 2), ("speed", 1)]);
+        assert_eq!(
+            registry
+                .ready_evolutions(&ready)
+                .iter()
+                .map(|upgrade| &upgrade.id)
+                .collect::<Vec<_>>(),
+            vec![&evolution]
+        );
+        assert_eq!(rolled_ids(&registry, &ready)[0], evolution.0);
+
+        let evolved = stacks(&[("blades", 2), ("speed", 1), ("blade_storm", 1)]);
+        assert!(registry.ready_evolutions(&evolved).is_empty());
+        assert!(!rolled_ids(&registry, &evolved).contains(&evolution.0));
+    }
+
+    #[test]
+    fn banished_evolution_is_not_offered() {
+        let registry = registry();
+        let ready = stacks(&[("blades", 2), ("speed", 1)]);
+        let banished: HashSet<UpgradeId> = [UpgradeId("blade_storm".to_string())].into();
+        let rolled = registry.roll(&ready, &banished, 3, 0., &mut StdRng::seed_from_u64(7));
+        assert!(rolled.iter().all(|upgrade| upgrade.id.0 != "blade_storm"));
+    }
+
+    #[test]
+    fn base_recipes_reference_matching_weapons() {
+        let registry: UpgradeRegistry =
+            ron::de::from_bytes(&std::fs::read("assets/base.upgrades.ron").unwrap()).unwrap();
+        assert!(!registry.evolutions.is_empty());
+        registry.evolutions.iter().for_each(|recipe| {
+            let weapon = registry.get(&recipe.weapon).and_then(|base| base.weapon);
+            let result = registry
+                .get(&recipe.result)
+                .and_then(|result| result.weapon);
+            assert!(registry.get(&recipe.passive).unwrap().passive, "{recipe:?}");
+            assert!(weapon.is_some(), "{recipe:?}");
+            assert_eq!(result.map(|kind| kind.pattern()), weapon, "{recipe:?}");
+        });
+    }
+}
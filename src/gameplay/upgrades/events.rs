@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+use super::assets::UpgradeId;
+
+#[derive(Event)]
+pub struct UpgradeStatEvent {
+    pub id: UpgradeId,
+}
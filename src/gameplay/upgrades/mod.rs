@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{states::GameState, system_sets::RunSet, systems::clear_events};
+
+use self::{
+    assets::{UpgradeRegistry, UpgradeRegistryLoader},
+    events::UpgradeStatEvent,
+    resources::{BanishedUpgrades, UpgradeCharges, UpgradeOffer, UpgradeStacks},
+    systems::{
+        load_upgrade_registry, process_upgrade_event, reset_upgrade_charges, reset_upgrade_stacks,
+    },
+};
+
+pub mod assets;
+pub mod events;
+pub mod resources;
+pub mod systems;
+
+/// How many passive items the player can carry at once.
+pub const MAX_PASSIVES: usize = 4;
+
+pub struct UpgradesPlugin;
+
+impl Plugin for UpgradesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<UpgradeRegistry>()
+            .init_asset_loader::<UpgradeRegistryLoader>()
+            .init_resource::<UpgradeStacks>()
+            .init_resource::<BanishedUpgrades>()
+            .init_resource::<UpgradeOffer>()
+            .init_resource::<UpgradeCharges>()
+            .add_event::<UpgradeStatEvent>()
+            .add_systems(Startup, load_upgrade_registry)
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (
+                    reset_upgrade_stacks,
+                    reset_upgrade_charges,
+                    clear_events::<UpgradeStatEvent>,
+                )
+                    .in_set(RunSet::Reset),
+            )
+            .add_systems(
+                Update,
+                (process_upgrade_event).run_if(in_state(GameState::Upgrading)),
+            );
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use rand::Rng;
+
+use super::assets::{UpgradeId, UpgradeRegistry};
+
+pub const OFFER_SIZE: usize = 3;
+
+#[derive(Resource)]
+pub struct UpgradeRegistryHandle(pub Handle<UpgradeRegistry>);
+
+#[derive(Resource, Default)]
+pub struct UpgradeStacks(pub HashMap<UpgradeId, u32>);
+
+/// Upgrades removed from the pool for the rest of the run.
+#[derive(Resource, Default)]
+pub struct BanishedUpgrades(pub HashSet<UpgradeId>);
+
+/// The upgrades currently offered on the level-up screen.
+#[derive(Resource, Default)]
+pub struct UpgradeOffer(pub Vec<UpgradeId>);
+
+impl UpgradeOffer {
+    /// Tops the offer back up to [`OFFER_SIZE`] without repeating offered or banished upgrades.
+    pub fn fill<R: Rng + ?Sized>(
+        &mut self,
+        registry: &UpgradeRegistry,
+        stacks: &UpgradeStacks,
+        banished: &BanishedUpgrades,
+        luck: f32,
+        rng: &mut R,
+    ) {
+        self.fill_excluding(registry, stacks, banished, &[], luck, rng);
+    }
+
+    /// Replaces the whole offer, preferring upgrades that weren't offered before.
+    pub fn reroll<R: Rng + ?Sized>(
+        &mut self,
+        registry: &UpgradeRegistry,
+        stacks: &UpgradeStacks,
+        banished: &BanishedUpgrades,
+        luck: f32,
+        rng: &mut R,
+    ) {
+        let previous = std::mem::take(&mut self.0);
+        self.fill_excluding(registry, stacks, banished, &previous, luck, rng);
+        self.fill(registry, stacks, banished, luck, rng);
+    }
+
+    fn fill_excluding<R: Rng + ?Sized>(
+        &mut self,
+        registry: &UpgradeRegistry,
+        stacks: &UpgradeStacks,
+        banished: &BanishedUpgrades,
+        also_excluded: &[UpgradeId],
+        luck: f32,
+        rng: &mut R,
+    ) {
+        let excluded: HashSet<UpgradeId> = banished
+            .0
+            .iter()
+            .chain(&self.0)
+            .chain(also_excluded)
+            .cloned()
+            .collect();
+        let rolled: Vec<UpgradeId> = registry
+            .roll(
+                &stacks.0,
+                &excluded,
+                OFFER_SIZE.saturating_sub(self.0.len()),
+                luck,
+                rng,
+            )
+            .into_iter()
+            .map(|upgrade| upgrade.id.clone())
+            .collect();
+        self.0.extend(rolled);
+    }
+}
+
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpgradeCharges {
+    pub rerolls: u32,
+    pub banishes: u32,
+    pub skips: u32,
+}
+
+impl UpgradeCharges {
+    /// Starting charges for a run, growing slowly with the number of runs played.
+    pub fn for_runs_played(runs_played: u32) -> Self {
+        Self {
+            rerolls: 2 + (runs_played / 5).min(3),
+            banishes: 1 + (runs_played / 10).min(2),
+            skips: 1 + (runs_played / 10).min(2),
+        }
+    }
+}
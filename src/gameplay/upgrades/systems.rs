@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::{
+        player::components::Player,
+        states::GameState,
+        stats::components::{ModifierSource, StatModifier, Stats},
+    },
+    ui::menus::stats_menu::resources::LifetimeStats,
+};
+
+use super::{
+    assets::UpgradeRegistry,
+    events::UpgradeStatEvent,
+    resources::{
+        BanishedUpgrades, UpgradeCharges, UpgradeOffer, UpgradeRegistryHandle, UpgradeStacks,
+    },
+};
+
+pub fn load_upgrade_registry(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(UpgradeRegistryHandle(
+        asset_server.load("base.upgrades.ron"),
+    ));
+}
+
+pub fn reset_upgrade_stacks(
+    mut stacks: ResMut<UpgradeStacks>,
+    mut banished: ResMut<BanishedUpgrades>,
+    mut offer: ResMut<UpgradeOffer>,
+) {
+    *stacks = UpgradeStacks::default();
+    *banished = BanishedUpgrades::default();
+    *offer = UpgradeOffer::default();
+}
+
+pub fn reset_upgrade_charges(
+    mut charges: ResMut<UpgradeCharges>,
+    lifetime_stats: Res<LifetimeStats>,
+) {
+    *charges = UpgradeCharges::for_runs_played(lifetime_stats.runs_played);
+}
+
+pub fn process_upgrade_event(
+    mut ev_reader: EventReader<UpgradeStatEvent>,
+    mut upgrade_query: Query<&mut Stats, With<Player>>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    mut stacks: ResMut<UpgradeStacks>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(registry) = registries.get(&registry_handle.0) else {
+        return;
+    };
+    if let Ok(mut stats) = upgrade_query.get_single_mut() {
+        ev_reader.read().for_each(|UpgradeStatEvent { id }| {
+            let Some(upgrade) = registry.get(id) else {
+                // Stay in the menu so the pick isn't silently lost
+                warn!("Unknown upgrade picked: {}", id.0);
+                return;
+            };
+            upgrade.modifiers.iter().for_each(|modifier| {
+                stats.add_modifier(StatModifier::permanent(
+                    modifier.stat,
+                    modifier.kind,
+                    modifier.value,
+                    ModifierSource::Upgrade(id.clone()),
+                ))
+            });
+            *stacks.0.entry(id.clone()).or_insert(0) += 1;
+            next_state.set(GameState::Running);
+        });
+    }
+}
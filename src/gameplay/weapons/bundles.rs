@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::RemoveOnReset;
+
+use super::components::{ContactDamage, Weapon, WeaponCooldown, WeaponEffect, WeaponKind};
+
+#[derive(Bundle)]
+pub struct WeaponBundle {
+    weapon: Weapon,
+    cooldown: WeaponCooldown,
+    spatial: SpatialBundle,
+}
+
+impl WeaponBundle {
+    pub fn new(kind: WeaponKind) -> Self {
+        Self {
+            weapon: Weapon { kind, level: 1 },
+            cooldown: WeaponCooldown(Timer::from_seconds(
+                kind.stats(1).cooldown,
+                TimerMode::Repeating,
+            )),
+            spatial: SpatialBundle::default(),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct WeaponProjectileBundle {
+    contact: ContactDamage,
+    sprite: SpriteBundle,
+    remove_on_reset: RemoveOnReset,
+}
+
+impl WeaponProjectileBundle {
+    pub fn new(kind: WeaponKind, size: f32, position: Vec2, contact: ContactDamage) -> Self {
+        Self {
+            contact,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: kind.color(),
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(0.)),
+                ..default()
+            },
+            remove_on_reset: RemoveOnReset,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct WeaponEffectBundle {
+    effect: WeaponEffect,
+    sprite: SpriteBundle,
+    remove_on_reset: RemoveOnReset,
+}
+
+impl WeaponEffectBundle {
+    pub fn new(kind: WeaponKind, position: Vec2, start_size: Vec2, end_size: Vec2) -> Self {
+        Self::colored(kind.color(), position, start_size, end_size)
+    }
+
+    pub fn colored(color: Color, position: Vec2, start_size: Vec2, end_size: Vec2) -> Self {
+        Self {
+            effect: WeaponEffect {
+                timer: Timer::from_seconds(0.3, TimerMode::Once),
+                start_size,
+                end_size,
+                alpha: color.a(),
+            },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(start_size),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(-1.)),
+                ..default()
+            },
+            remove_on_reset: RemoveOnReset,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WeaponKind {
+    OrbitingBlades,
+    Aura,
+    Boomerang,
+    Lightning,
+    Nova,
+    BladeStorm,
+    SoulEater,
+    Glaive,
+    ThunderLoop,
+    Supernova,
+}
+
+pub struct WeaponStats {
+    pub damage: i32,
+    pub cooldown: f32,
+    pub area: f32,
+    pub count: u32,
+}
+
+impl WeaponKind {
+    /// The firing pattern this weapon uses; evolutions keep the pattern of their base weapon.
+    pub fn pattern(&self) -> WeaponKind {
+        match self {
+            WeaponKind::BladeStorm => WeaponKind::OrbitingBlades,
+            WeaponKind::SoulEater => WeaponKind::Aura,
+            WeaponKind::Glaive => WeaponKind::Boomerang,
+            WeaponKind::ThunderLoop => WeaponKind::Lightning,
+            WeaponKind::Supernova => WeaponKind::Nova,
+            kind => *kind,
+        }
+    }
+
+    pub fn stats(&self, level: u32) -> WeaponStats {
+        let step = level.saturating_sub(1);
+        match self {
+            WeaponKind::OrbitingBlades => WeaponStats {
+                damage: 4 + 2 * step as i32,
+                cooldown: 0.5,
+                area: 70. + 5. * step as f32,
+                count: 1 + level / 2,
+            },
+            WeaponKind::Aura => WeaponStats {
+                damage: 2 + step as i32,
+                cooldown: 0.5,
+                area: 80. + 15. * step as f32,
+                count: 1,
+            },
+            WeaponKind::Boomerang => WeaponStats {
+                damage: 8 + 3 * step as i32,
+                cooldown: (2. - 0.2 * step as f32).max(0.5),
+                area: 300.,
+                count: 1 + step / 2,
+            },
+            WeaponKind::Lightning => WeaponStats {
+                damage: 12 + 4 * step as i32,
+                cooldown: (2.5 - 0.25 * step as f32).max(0.5),
+                area: 400.,
+                count: 1 + step / 2,
+            },
+            WeaponKind::Nova => WeaponStats {
+                damage: 10 + 4 * step as i32,
+                cooldown: (3. - 0.3 * step as f32).max(0.5),
+                area: 150. + 20. * step as f32,
+                count: 1,
+            },
+            WeaponKind::BladeStorm => WeaponStats {
+                damage: 18,
+                cooldown: 0.3,
+                area: 100.,
+                count: 6,
+            },
+            WeaponKind::SoulEater => WeaponStats {
+                damage: 8,
+                cooldown: 0.3,
+                area: 160.,
+                count: 1,
+            },
+            WeaponKind::Glaive => WeaponStats {
+                damage: 25,
+                cooldown: 0.8,
+                area: 450.,
+                count: 4,
+            },
+            WeaponKind::ThunderLoop => WeaponStats {
+                damage: 35,
+                cooldown: 1.,
+                area: 500.,
+                count: 4,
+            },
+            WeaponKind::Supernova => WeaponStats {
+                damage: 40,
+                cooldown: 1.5,
+                area: 280.,
+                count: 1,
+            },
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            WeaponKind::OrbitingBlades => Color::SILVER,
+            WeaponKind::Aura => Color::rgba(0.9, 0.4, 1.0, 0.15),
+            WeaponKind::Boomerang => Color::ORANGE,
+            WeaponKind::Lightning => Color::YELLOW,
+            WeaponKind::Nova => Color::rgba(0.4, 0.8, 1.0, 0.5),
+            WeaponKind::BladeStorm => Color::WHITE,
+            WeaponKind::SoulEater => Color::rgba(1.0, 0.2, 0.4, 0.2),
+            WeaponKind::Glaive => Color::GOLD,
+            WeaponKind::ThunderLoop => Color::CYAN,
+            WeaponKind::Supernova => Color::rgba(1.0, 0.9, 0.5, 0.6),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Weapon {
+    pub kind: WeaponKind,
+    pub level: u32,
+}
+
+#[derive(Component)]
+pub struct WeaponCooldown(pub Timer);
+
+#[derive(Component)]
+pub struct OrbitingBlade {
+    pub offset: f32,
+}
+
+#[derive(Component)]
+pub struct AuraField;
+
+#[derive(Component)]
+pub struct Boomerang {
+    pub direction: Vec2,
+    pub range: f32,
+    pub travelled: f32,
+    pub returning: bool,
+}
+
+/// Damages enemies it touches, at most once per `hit_interval` for each enemy.
+#[derive(Component)]
+pub struct ContactDamage {
+    pub damage: i32,
+    pub hit_interval: f32,
+    pub recent_hits: HashMap<Entity, Timer>,
+}
+
+impl ContactDamage {
+    pub fn new(damage: i32, hit_interval: f32) -> Self {
+        Self {
+            damage,
+            hit_interval,
+            recent_hits: HashMap::new(),
+        }
+    }
+}
+
+/// Short-lived visual that resizes and fades out, like a lightning flash or a nova ring.
+#[derive(Component)]
+pub struct WeaponEffect {
+    pub timer: Timer,
+    pub start_size: Vec2,
+    pub end_size: Vec2,
+    pub alpha: f32,
+}
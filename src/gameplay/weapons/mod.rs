@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+use self::systems::{
+    animate_weapon_effects, apply_contact_damage, equip_weapons, move_boomerangs, orbit_blades,
+    pulse_aura, release_nova, spin_aura, strike_lightning, sync_weapons, throw_boomerangs,
+    tick_weapon_cooldowns,
+};
+
+pub mod bundles;
+pub mod components;
+pub mod systems;
+
+/// How many weapons the player can carry at once.
+pub const MAX_WEAPONS: usize = 4;
+
+pub struct WeaponsPlugin;
+
+impl Plugin for WeaponsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (equip_weapons, sync_weapons).chain())
+            .add_systems(
+                Update,
+                (
+                    tick_weapon_cooldowns,
+                    (pulse_aura, throw_boomerangs, strike_lightning, release_nova),
+                    (orbit_blades, spin_aura, move_boomerangs),
+                    apply_contact_damage,
+                    animate_weapon_effects,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
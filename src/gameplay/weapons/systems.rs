@@ -0,0 +1,454 @@
+use std::f32::consts::TAU;
+
+use bevy::{
+    math::bounding::{Aabb2d, IntersectsVolume},
+    prelude::*,
+};
+use rand::{seq::SliceRandom, thread_rng};
+
+use crate::{
+    events::DamageEvent,
+    gameplay::{
+        components::{CooldownReduction, CritChance, Damage, Dead, Health, Shooter},
+        enemies::components::Enemy,
+        player::components::Player,
+        systems::roll_crit,
+        upgrades::{
+            assets::UpgradeRegistry, events::UpgradeStatEvent, resources::UpgradeRegistryHandle,
+        },
+    },
+};
+
+use super::{
+    bundles::{WeaponBundle, WeaponEffectBundle, WeaponProjectileBundle},
+    components::{
+        AuraField, Boomerang, ContactDamage, OrbitingBlade, Weapon, WeaponCooldown, WeaponEffect,
+        WeaponKind,
+    },
+};
+
+const ORBIT_SPEED: f32 = 3.;
+const BOOMERANG_SPEED: f32 = 400.;
+
+fn strike(
+    target: Entity,
+    position: Vec2,
+    health: &mut Health,
+    damage: i32,
+    crit_chance: &CritChance,
+    ev_damage: &mut EventWriter<DamageEvent>,
+) {
+    let (Damage(amount), critical) = roll_crit(&Damage(damage), crit_chance);
+    health.0 -= amount;
+    ev_damage.send(DamageEvent {
+        target,
+        position,
+        amount,
+        dealt_by: Shooter::Player,
+        critical,
+    });
+}
+
+pub fn equip_weapons(
+    mut ev_upgrade: EventReader<UpgradeStatEvent>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    q_player: Query<Entity, With<Player>>,
+    mut q_weapons: Query<&mut Weapon>,
+    mut commands: Commands,
+) {
+    let Some(registry) = registries.get(&registry_handle.0) else {
+        return;
+    };
+    let Ok(player) = q_player.get_single() else {
+        return;
+    };
+    ev_upgrade.read().for_each(|UpgradeStatEvent { id }| {
+        let Some(kind) = registry.get(id).and_then(|upgrade| upgrade.weapon) else {
+            return;
+        };
+        // Evolutions replace their base weapon in place instead of taking a new slot
+        let replaces = registry
+            .evolution_for(id)
+            .and_then(|recipe| registry.get(&recipe.weapon))
+            .and_then(|base| base.weapon);
+        match q_weapons
+            .iter_mut()
+            .find(|weapon| Some(weapon.kind) == replaces || weapon.kind == kind)
+        {
+            Some(mut weapon) if weapon.kind == kind => weapon.level += 1,
+            Some(mut weapon) => *weapon = Weapon { kind, level: 1 },
+            None => {
+                commands.entity(player).with_children(|parent| {
+                    parent.spawn(WeaponBundle::new(kind));
+                });
+            }
+        }
+    });
+}
+
+pub fn sync_weapons(
+    mut q_weapons: Query<(&Weapon, &mut WeaponCooldown, Entity), Changed<Weapon>>,
+    mut commands: Commands,
+) {
+    q_weapons
+        .iter_mut()
+        .for_each(|(weapon, mut cooldown, entity)| {
+            let stats = weapon.kind.stats(weapon.level);
+            cooldown
+                .0
+                .set_duration(std::time::Duration::from_secs_f32(stats.cooldown));
+            match weapon.kind.pattern() {
+                WeaponKind::OrbitingBlades => {
+                    commands
+                        .entity(entity)
+                        .despawn_descendants()
+                        .with_children(|parent| {
+                            (0..stats.count).for_each(|i| {
+                                parent.spawn((
+                                    OrbitingBlade {
+                                        offset: TAU * i as f32 / stats.count as f32,
+                                    },
+                                    ContactDamage::new(stats.damage, stats.cooldown),
+                                    SpriteBundle {
+                                        sprite: Sprite {
+                                            color: weapon.kind.color(),
+                                            custom_size: Some(Vec2::splat(16.)),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                ));
+                            });
+                        });
+                }
+                WeaponKind::Aura => {
+                    commands
+                        .entity(entity)
+                        .despawn_descendants()
+                        .with_children(|parent| {
+                            parent.spawn((
+                                AuraField,
+                                SpriteBundle {
+                                    sprite: Sprite {
+                                        color: weapon.kind.color(),
+                                        custom_size: Some(Vec2::splat(stats.area * 2.)),
+                                        ..default()
+                                    },
+                                    transform: Transform::from_xyz(0., 0., -1.),
+                                    ..default()
+                                },
+                            ));
+                        });
+                }
+                _ => {}
+            }
+        });
+}
+
+pub fn tick_weapon_cooldowns(
+    mut q_cooldowns: Query<&mut WeaponCooldown>,
+    q_player: Query<&CooldownReduction, With<Player>>,
+    time: Res<Time>,
+) {
+    let reduction = q_player.get_single().map_or(0., |reduction| reduction.0);
+    let delta = time.delta().div_f32(1. - reduction);
+    q_cooldowns.iter_mut().for_each(|mut cooldown| {
+        cooldown.0.tick(delta);
+    });
+}
+
+pub fn orbit_blades(
+    mut q_blades: Query<(&mut Transform, &OrbitingBlade, &Parent)>,
+    q_weapons: Query<&Weapon>,
+    time: Res<Time>,
+) {
+    q_blades
+        .iter_mut()
+        .for_each(|(mut transform, blade, parent)| {
+            if let Ok(weapon) = q_weapons.get(parent.get()) {
+                let radius = weapon.kind.stats(weapon.level).area;
+                let angle = time.elapsed_seconds() * ORBIT_SPEED + blade.offset;
+                transform.translation = (Vec2::from_angle(angle) * radius).extend(0.);
+                transform.rotation = Quat::from_rotation_z(angle);
+            }
+        });
+}
+
+pub fn spin_aura(mut q_aura: Query<&mut Transform, With<AuraField>>, time: Res<Time>) {
+    q_aura.iter_mut().for_each(|mut transform| {
+        transform.rotate_z(time.delta_seconds());
+    });
+}
+
+pub fn pulse_aura(
+    q_weapons: Query<(&Weapon, &WeaponCooldown)>,
+    q_player: Query<(&Transform, &CritChance), With<Player>>,
+    mut q_enemies: Query<(&Transform, &mut Health, Entity), (With<Enemy>, Without<Dead>)>,
+    mut ev_damage: EventWriter<DamageEvent>,
+) {
+    let Ok((player_tr, crit_chance)) = q_player.get_single() else {
+        return;
+    };
+    let player_pos = player_tr.translation.xy();
+    q_weapons
+        .iter()
+        .filter(|(weapon, cooldown)| {
+            weapon.kind.pattern() == WeaponKind::Aura && cooldown.0.just_finished()
+        })
+        .for_each(|(weapon, _)| {
+            let stats = weapon.kind.stats(weapon.level);
+            q_enemies
+                .iter_mut()
+                .filter(|(enemy_tr, health, _)| {
+                    health.0 > 0 && enemy_tr.translation.xy().distance(player_pos) <= stats.area
+                })
+                .for_each(|(enemy_tr, mut health, entity)| {
+                    strike(
+                        entity,
+                        enemy_tr.translation.xy(),
+                        &mut health,
+                        stats.damage,
+                        crit_chance,
+                        &mut ev_damage,
+                    );
+                });
+        });
+}
+
+pub fn throw_boomerangs(
+    q_weapons: Query<(&Weapon, &WeaponCooldown)>,
+    q_player: Query<&Transform, With<Player>>,
+    q_enemies: Query<&Transform, (With<Enemy>, Without<Dead>)>,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    let player_pos = player_tr.translation.xy();
+    q_weapons
+        .iter()
+        .filter(|(weapon, cooldown)| {
+            weapon.kind.pattern() == WeaponKind::Boomerang && cooldown.0.just_finished()
+        })
+        .for_each(|(weapon, _)| {
+            let stats = weapon.kind.stats(weapon.level);
+            let nearest = q_enemies
+                .iter()
+                .map(|enemy_tr| enemy_tr.translation.xy())
+                .filter(|enemy_pos| enemy_pos.distance(player_pos) <= stats.area)
+                .min_by(|a, b| {
+                    a.distance_squared(player_pos)
+                        .total_cmp(&b.distance_squared(player_pos))
+                });
+            if let Some(target) = nearest {
+                let aim = (target - player_pos).normalize_or_zero();
+                (0..stats.count).for_each(|i| {
+                    let spread = (i as f32 - (stats.count - 1) as f32 / 2.) * 0.3;
+                    commands.spawn((
+                        Boomerang {
+                            direction: Vec2::from_angle(spread).rotate(aim),
+                            range: stats.area,
+                            travelled: 0.,
+                            returning: false,
+                        },
+                        WeaponProjectileBundle::new(
+                            weapon.kind,
+                            14.,
+                            player_pos,
+                            ContactDamage::new(stats.damage, 0.4),
+                        ),
+                    ));
+                });
+            }
+        });
+}
+
+pub fn move_boomerangs(
+    mut q_boomerangs: Query<(&mut Transform, &mut Boomerang, Entity), Without<Player>>,
+    q_player: Query<&Transform, With<Player>>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let Ok(player_tr) = q_player.get_single() else {
+        return;
+    };
+    let player_pos = player_tr.translation.xy();
+    let step = BOOMERANG_SPEED * time.delta_seconds();
+    q_boomerangs
+        .iter_mut()
+        .for_each(|(mut transform, mut boomerang, entity)| {
+            let position = transform.translation.xy();
+            if boomerang.returning {
+                if position.distance(player_pos) <= step {
+                    commands.entity(entity).despawn();
+                    return;
+                }
+                let back = (player_pos - position).normalize_or_zero();
+                transform.translation += (back * step).extend(0.);
+            } else {
+                transform.translation += (boomerang.direction * step).extend(0.);
+                boomerang.travelled += step;
+                boomerang.returning = boomerang.travelled >= boomerang.range;
+            }
+            transform.rotate_z(time.delta_seconds() * 12.);
+        });
+}
+
+pub fn strike_lightning(
+    q_weapons: Query<(&Weapon, &WeaponCooldown)>,
+    q_player: Query<(&Transform, &CritChance), With<Player>>,
+    mut q_enemies: Query<(&Transform, &mut Health, Entity), (With<Enemy>, Without<Dead>)>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    mut commands: Commands,
+) {
+    let Ok((player_tr, crit_chance)) = q_player.get_single() else {
+        return;
+    };
+    let player_pos = player_tr.translation.xy();
+    q_weapons
+        .iter()
+        .filter(|(weapon, cooldown)| {
+            weapon.kind.pattern() == WeaponKind::Lightning && cooldown.0.just_finished()
+        })
+        .for_each(|(weapon, _)| {
+            let stats = weapon.kind.stats(weapon.level);
+            let in_range: Vec<Entity> = q_enemies
+                .iter()
+                .filter(|(enemy_tr, health, _)| {
+                    health.0 > 0 && enemy_tr.translation.xy().distance(player_pos) <= stats.area
+                })
+                .map(|(_, _, entity)| entity)
+                .collect();
+            in_range
+                .choose_multiple(&mut thread_rng(), stats.count as usize)
+                .for_each(|target| {
+                    if let Ok((enemy_tr, mut health, entity)) = q_enemies.get_mut(*target) {
+                        let position = enemy_tr.translation.xy();
+                        strike(
+                            entity,
+                            position,
+                            &mut health,
+                            stats.damage,
+                            crit_chance,
+                            &mut ev_damage,
+                        );
+                        commands.spawn(WeaponEffectBundle::new(
+                            weapon.kind,
+                            position + Vec2::Y * 30.,
+                            Vec2::new(8., 60.),
+                            Vec2::new(4., 60.),
+                        ));
+                    }
+                });
+        });
+}
+
+pub fn release_nova(
+    q_weapons: Query<(&Weapon, &WeaponCooldown)>,
+    q_player: Query<(&Transform, &CritChance), With<Player>>,
+    mut q_enemies: Query<(&Transform, &mut Health, Entity), (With<Enemy>, Without<Dead>)>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    mut commands: Commands,
+) {
+    let Ok((player_tr, crit_chance)) = q_player.get_single() else {
+        return;
+    };
+    let player_pos = player_tr.translation.xy();
+    q_weapons
+        .iter()
+        .filter(|(weapon, cooldown)| {
+            weapon.kind.pattern() == WeaponKind::Nova && cooldown.0.just_finished()
+        })
+        .for_each(|(weapon, _)| {
+            let stats = weapon.kind.stats(weapon.level);
+            q_enemies
+                .iter_mut()
+                .filter(|(enemy_tr, health, _)| {
+                    health.0 > 0 && enemy_tr.translation.xy().distance(player_pos) <= stats.area
+                })
+                .for_each(|(enemy_tr, mut health, entity)| {
+                    strike(
+                        entity,
+                        enemy_tr.translation.xy(),
+                        &mut health,
+                        stats.damage,
+                        crit_chance,
+                        &mut ev_damage,
+                    );
+                });
+            commands.spawn(WeaponEffectBundle::new(
+                weapon.kind,
+                player_pos,
+                Vec2::ZERO,
+                Vec2::splat(stats.area * 2.),
+            ));
+        });
+}
+
+pub fn apply_contact_damage(
+    mut q_hitters: Query<(&GlobalTransform, &Sprite, &mut ContactDamage)>,
+    q_player: Query<&CritChance, With<Player>>,
+    mut q_enemies: Query<(&Transform, &Sprite, &mut Health, Entity), (With<Enemy>, Without<Dead>)>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    let Ok(crit_chance) = q_player.get_single() else {
+        return;
+    };
+    q_hitters
+        .iter_mut()
+        .for_each(|(hitter_tr, hitter_sprite, mut contact)| {
+            contact
+                .recent_hits
+                .retain(|_, timer| !timer.tick(time.delta()).finished());
+            let hitter_box = Aabb2d::new(
+                hitter_tr.translation().xy(),
+                hitter_sprite.custom_size.unwrap_or_default() * 0.5,
+            );
+            q_enemies
+                .iter_mut()
+                .for_each(|(enemy_tr, enemy_sprite, mut health, entity)| {
+                    let enemy_box = Aabb2d::new(
+                        enemy_tr.translation.xy(),
+                        enemy_sprite.custom_size.unwrap_or_default() * 0.5,
+                    );
+                    if health.0 > 0
+                        && !contact.recent_hits.contains_key(&entity)
+                        && hitter_box.intersects(&enemy_box)
+                    {
+                        strike(
+                            entity,
+                            enemy_tr.translation.xy(),
+                            &mut health,
+                            contact.damage,
+                            crit_chance,
+                            &mut ev_damage,
+                        );
+                        let interval = contact.hit_interval;
+                        contact
+                            .recent_hits
+                            .insert(entity, Timer::from_seconds(interval, TimerMode::Once));
+                    }
+                });
+        });
+}
+
+pub fn animate_weapon_effects(
+    mut q_effects: Query<(&mut Sprite, &mut WeaponEffect, Entity)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    q_effects
+        .iter_mut()
+        .for_each(|(mut sprite, mut effect, entity)| {
+            effect.timer.tick(time.delta());
+            if effect.timer.finished() {
+                commands.entity(entity).despawn();
+                return;
+            }
+            let progress = effect.timer.fraction();
+            sprite.custom_size = Some(effect.start_size.lerp(effect.end_size, progress));
+            sprite.color.set_a(effect.alpha * (1. - progress));
+        });
+}
@@ -1,6 +1,14 @@
+#![allow(
+    clippy::type_complexity,
+    clippy::too_many_arguments,
+    clippy::single_match
+)]
+
 mod components;
 mod events;
 mod gameplay;
+mod persistence;
+mod settings;
 mod systems;
 mod ui;
 
@@ -8,11 +16,12 @@ use bevy::prelude::*;
 
 use events::*;
 use gameplay::GameplayPlugin;
+use settings::SettingsPlugin;
 
 use ui::UiPlugin;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, UiPlugin, GameplayPlugin))
+        .add_plugins((DefaultPlugins, SettingsPlugin, UiPlugin, GameplayPlugin))
         .run();
 }
@@ -0,0 +1,51 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Location of a file in the game's config directory.
+pub fn config_path(file: &str) -> Option<PathBuf> {
+    // Tests run the real plugins and must not touch the player's files
+    if cfg!(test) {
+        return None;
+    }
+    dirs::config_dir().map(|dir| dir.join("cubes-attack").join(file))
+}
+
+/// Reads a RON config file, falling back to the default when it is missing or invalid.
+pub fn load_ron<T: DeserializeOwned + Default>(file: &str) -> T {
+    let Some(path) = config_path(file) else {
+        return T::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+            warn!("Failed to parse {}: {err}", path.display());
+            T::default()
+        }),
+        Err(_) => T::default(),
+    }
+}
+
+pub fn save_ron<T: Serialize>(file: &str, value: &T) {
+    if let Some(path) = config_path(file) {
+        if let Err(err) = write_ron(&path, value) {
+            warn!("Failed to save {}: {err}", path.display());
+        }
+    }
+}
+
+fn write_ron<T: Serialize>(path: &Path, value: &T) -> Result<(), Box<dyn Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(
+        path,
+        ron::ser::to_string_pretty(value, PrettyConfig::default())?,
+    )?;
+    Ok(())
+}
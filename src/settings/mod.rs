@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+use self::{
+    resources::Settings,
+    systems::{apply_settings, save_settings},
+};
+
+pub mod resources;
+pub mod systems;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load())
+            .add_systems(OnExit(GameState::Settings), save_settings)
+            .add_systems(
+                Update,
+                (apply_settings).run_if(resource_changed::<Settings>),
+            );
+    }
+}
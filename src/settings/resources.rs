@@ -0,0 +1,98 @@
+use bevy::{prelude::*, window::WindowMode};
+use serde::{Deserialize, Serialize};
+
+use crate::persistence::{load_ron, save_ron};
+
+pub const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowModeSetting {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    pub fn next(self) -> Self {
+        match self {
+            WindowModeSetting::Windowed => WindowModeSetting::Borderless,
+            WindowModeSetting::Borderless => WindowModeSetting::Fullscreen,
+            WindowModeSetting::Fullscreen => WindowModeSetting::Windowed,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            WindowModeSetting::Windowed => "Windowed",
+            WindowModeSetting::Borderless => "Borderless",
+            WindowModeSetting::Fullscreen => "Fullscreen",
+        }
+    }
+}
+
+impl From<WindowModeSetting> for WindowMode {
+    fn from(mode: WindowModeSetting) -> Self {
+        match mode {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen,
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub window_mode: WindowModeSetting,
+    pub resolution: (u32, u32),
+    pub vsync: bool,
+    pub ui_scale: f32,
+    pub screen_shake: f32,
+    pub damage_numbers: bool,
+    pub auto_fire: bool,
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            window_mode: WindowModeSetting::Windowed,
+            resolution: RESOLUTIONS[0],
+            vsync: true,
+            ui_scale: 1.,
+            screen_shake: 1.,
+            damage_numbers: true,
+            auto_fire: false,
+            master_volume: 1.,
+            music_volume: 0.8,
+            sfx_volume: 0.8,
+        }
+    }
+}
+
+impl Settings {
+    const FILE: &'static str = "settings.ron";
+
+    pub fn load() -> Self {
+        load_ron(Self::FILE)
+    }
+
+    pub fn save(&self) {
+        save_ron(Self::FILE, self);
+    }
+
+    pub fn next_resolution(&self) -> (u32, u32) {
+        RESOLUTIONS
+            .iter()
+            .position(|res| *res == self.resolution)
+            .map_or(RESOLUTIONS[0], |idx| {
+                RESOLUTIONS[(idx + 1) % RESOLUTIONS.len()]
+            })
+    }
+
+    pub fn resolution_label(&self) -> String {
+        format!("{w}x{h}", w = self.resolution.0, h = self.resolution.1)
+    }
+}
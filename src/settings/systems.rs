@@ -0,0 +1,37 @@
+use bevy::{
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, WindowMode},
+};
+
+use super::resources::Settings;
+
+pub fn apply_settings(
+    settings: Res<Settings>,
+    mut q_windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+    global_volume: Option<ResMut<GlobalVolume>>,
+) {
+    if let Ok(mut window) = q_windows.get_single_mut() {
+        window.mode = settings.window_mode.into();
+        if window.mode == WindowMode::Windowed {
+            window
+                .resolution
+                .set(settings.resolution.0 as f32, settings.resolution.1 as f32);
+        }
+        window.present_mode = if settings.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+    }
+
+    ui_scale.0 = settings.ui_scale;
+
+    if let Some(mut global_volume) = global_volume {
+        *global_volume = GlobalVolume::new(settings.master_volume);
+    }
+}
+
+pub fn save_settings(settings: Res<Settings>) {
+    settings.save();
+}
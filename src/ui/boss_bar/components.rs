@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct BossBar;
+
+#[derive(Component)]
+pub struct BossBarFill;
+
+#[derive(Component)]
+pub struct BossBarText;
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{states::GameState, system_sets::RunSet};
+
+use self::systems::layout::{spawn_boss_bar, update_boss_bar};
+
+pub mod components;
+pub mod systems;
+
+pub struct BossBarPlugin;
+
+impl Plugin for BossBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Starting),
+            (spawn_boss_bar).in_set(RunSet::Setup),
+        )
+        .add_systems(Update, update_boss_bar);
+    }
+}
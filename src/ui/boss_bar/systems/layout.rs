@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::{
+        components::{Health, MaxHealth, RemoveOnReset},
+        enemies::boss::components::Boss,
+    },
+    ui::{
+        boss_bar::components::{BossBar, BossBarFill, BossBarText},
+        widgets::{
+            label::{LabelWidget, TextSize},
+            panel::PanelWidget,
+            resources::Theme,
+        },
+    },
+};
+
+const BAR_WIDTH: f32 = 600.;
+const BAR_HEIGHT: f32 = 20.;
+
+fn fill_style(fraction: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.),
+        width: Val::Percent(fraction.clamp(0., 1.) * 100.),
+        height: Val::Percent(100.),
+        ..default()
+    }
+}
+
+pub fn spawn_boss_bar(mut commands: Commands, theme: Res<Theme>) {
+    PanelWidget::column()
+        .position(PositionType::Absolute)
+        .width(Val::Percent(100.))
+        .gap(4.)
+        .spawn(&mut commands, &theme)
+        .insert((BossBar, RemoveOnReset, Visibility::Hidden))
+        .with_children(|parent| {
+            LabelWidget::new("")
+                .size(TextSize::Small)
+                .spawn(parent, &theme)
+                .insert(BossBarText);
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    background_color: theme.button_bg.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: fill_style(1.),
+                            background_color: theme.epic_color.into(),
+                            ..default()
+                        },
+                        BossBarFill,
+                    ));
+                });
+        });
+}
+
+pub fn update_boss_bar(
+    q_boss: Query<(&Boss, &Health, &MaxHealth)>,
+    mut q_bar: Query<&mut Visibility, With<BossBar>>,
+    mut q_fill: Query<&mut Style, With<BossBarFill>>,
+    mut q_text: Query<&mut Text, With<BossBarText>>,
+) {
+    let boss = q_boss.iter().next();
+    q_bar.iter_mut().for_each(|mut visibility| {
+        let wanted = match boss {
+            Some(_) => Visibility::Inherited,
+            None => Visibility::Hidden,
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    });
+    if let Some((boss, health, max_health)) = boss {
+        q_fill.iter_mut().for_each(|mut style| {
+            *style = fill_style(health.0 as f32 / max_health.0 as f32);
+        });
+        q_text.iter_mut().for_each(|mut text| {
+            if text.sections[0].value != boss.kind.name() {
+                text.sections[0].value = boss.kind.name().to_string();
+            }
+        });
+    }
+}
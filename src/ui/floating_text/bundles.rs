@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::{RemoveOnReset, Shooter};
+
+use super::{
+    components::{DamageNumber, FloatingText},
+    styles::FloatingTextStyle,
+};
+
+#[derive(Bundle)]
+pub struct FloatingTextBundle {
+    pub text: Text2dBundle,
+    pub floating: FloatingText,
+    pub remove_on_reset: RemoveOnReset,
+}
+
+impl FloatingTextBundle {
+    pub fn new(value: String, style: TextStyle, position: Vec2) -> Self {
+        Self {
+            text: Text2dBundle {
+                text: Text::from_section(value, style).with_justify(JustifyText::Center),
+                transform: Transform::from_translation(position.extend(10.)),
+                ..default()
+            },
+            floating: FloatingText {
+                velocity: Vec2::Y * FloatingTextStyle::rise_speed(),
+                lifetime: Timer::from_seconds(FloatingTextStyle::lifetime(), TimerMode::Once),
+            },
+            remove_on_reset: RemoveOnReset,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct DamageNumberBundle {
+    pub floating: FloatingTextBundle,
+    pub damage_number: DamageNumber,
+}
+
+impl DamageNumberBundle {
+    pub fn new(
+        target: Entity,
+        amount: i32,
+        dealt_by: Shooter,
+        critical: bool,
+        position: Vec2,
+    ) -> Self {
+        Self {
+            floating: FloatingTextBundle::new(
+                format!("{amount}"),
+                FloatingTextStyle::damage_text_style(dealt_by, critical),
+                position,
+            ),
+            damage_number: DamageNumber {
+                target,
+                amount,
+                dealt_by,
+                critical,
+                batch_window: Timer::from_seconds(
+                    FloatingTextStyle::batch_window(),
+                    TimerMode::Once,
+                ),
+            },
+        }
+    }
+}
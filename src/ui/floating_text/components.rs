@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::Shooter;
+
+#[derive(Component)]
+pub struct FloatingText {
+    pub velocity: Vec2,
+    pub lifetime: Timer,
+}
+
+#[derive(Component)]
+pub struct DamageNumber {
+    pub target: Entity,
+    pub amount: i32,
+    pub dealt_by: Shooter,
+    pub critical: bool,
+    pub batch_window: Timer,
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::{gameplay::states::GameState, settings::resources::Settings};
+
+use self::systems::{float_texts, spawn_damage_numbers, spawn_kill_popups};
+
+pub mod bundles;
+pub mod components;
+pub mod styles;
+pub mod systems;
+
+pub struct FloatingTextPlugin;
+
+impl Plugin for FloatingTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (spawn_damage_numbers).run_if(|settings: Res<Settings>| settings.damage_numbers),
+                spawn_kill_popups,
+                float_texts,
+            )
+                .run_if(in_state(GameState::Running)),
+        );
+    }
+}
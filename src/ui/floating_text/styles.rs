@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use crate::gameplay::components::Shooter;
+
+pub struct FloatingTextStyle;
+
+impl FloatingTextStyle {
+    pub fn damage_text_style(dealt_by: Shooter, critical: bool) -> TextStyle {
+        TextStyle {
+            font_size: if critical { 28. } else { 20. },
+            color: Self::damage_color(dealt_by, critical),
+            ..default()
+        }
+    }
+
+    pub fn damage_color(dealt_by: Shooter, critical: bool) -> Color {
+        match (dealt_by, critical) {
+            (Shooter::Player, false) => Color::WHITE,
+            (Shooter::Player, true) => Color::ORANGE,
+            (Shooter::Enemy, false) => Color::RED,
+            (Shooter::Enemy, true) => Color::CRIMSON,
+        }
+    }
+
+    pub fn score_text_style() -> TextStyle {
+        TextStyle {
+            font_size: 24.,
+            color: Color::GOLD,
+            ..default()
+        }
+    }
+
+    pub fn lifetime() -> f32 {
+        0.8
+    }
+
+    pub fn batch_window() -> f32 {
+        0.25
+    }
+
+    pub fn rise_speed() -> f32 {
+        40.
+    }
+}
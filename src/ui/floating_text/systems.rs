@@ -0,0 +1,105 @@
+use bevy::{prelude::*, utils::HashMap};
+use rand::Rng;
+
+use crate::{
+    gameplay::components::{Health, PointWorth, Shooter},
+    DamageEvent,
+};
+
+use super::{
+    bundles::{DamageNumberBundle, FloatingTextBundle},
+    components::{DamageNumber, FloatingText},
+    styles::FloatingTextStyle,
+};
+
+pub fn spawn_damage_numbers(
+    mut ev_damage: EventReader<DamageEvent>,
+    mut q_numbers: Query<(&mut DamageNumber, &mut FloatingText, &mut Text)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    let mut pending: HashMap<Entity, (i32, Shooter, bool, Vec2)> = HashMap::new();
+    ev_damage.read().for_each(
+        |DamageEvent {
+             target,
+             position,
+             amount,
+             dealt_by,
+             critical,
+         }| {
+            let entry = pending
+                .entry(*target)
+                .or_insert((0, *dealt_by, false, *position));
+            entry.0 += amount;
+            entry.2 |= critical.0;
+        },
+    );
+
+    q_numbers
+        .iter_mut()
+        .for_each(|(mut number, mut floating, mut text)| {
+            if number.batch_window.tick(time.delta()).finished() {
+                return;
+            }
+            if let Some((amount, _, critical, _)) = pending.remove(&number.target) {
+                number.amount += amount;
+                number.critical |= critical;
+                floating.lifetime.reset();
+                text.sections[0].value = format!("{amount}", amount = number.amount);
+                text.sections[0].style =
+                    FloatingTextStyle::damage_text_style(number.dealt_by, number.critical);
+            }
+        });
+
+    let mut rng = rand::thread_rng();
+    pending
+        .into_iter()
+        .for_each(|(target, (amount, dealt_by, critical, position))| {
+            let offset = Vec2::new(rng.gen_range(-12. ..12.), 16.);
+            commands.spawn(DamageNumberBundle::new(
+                target,
+                amount,
+                dealt_by,
+                critical,
+                position + offset,
+            ));
+        });
+}
+
+pub fn spawn_kill_popups(
+    q_killed: Query<(&Transform, &Health, &PointWorth), Changed<Health>>,
+    mut commands: Commands,
+) {
+    q_killed
+        .iter()
+        .filter(|(_, Health(hp), _)| *hp <= 0)
+        .for_each(|(tr, _, PointWorth(points))| {
+            commands.spawn(FloatingTextBundle::new(
+                format!("+{points}"),
+                FloatingTextStyle::score_text_style(),
+                tr.translation.xy(),
+            ));
+        });
+}
+
+pub fn float_texts(
+    mut q_floating: Query<(&mut Transform, &mut Text, &mut FloatingText, Entity)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    q_floating
+        .iter_mut()
+        .for_each(|(mut tr, mut text, mut floating, entity)| {
+            floating.lifetime.tick(time.delta());
+            tr.translation += (floating.velocity * time.delta_seconds()).extend(0.);
+
+            let alpha = 1. - floating.lifetime.fraction();
+            text.sections.iter_mut().for_each(|section| {
+                section.style.color.set_a(alpha);
+            });
+
+            if floating.lifetime.finished() {
+                commands.entity(entity).despawn();
+            }
+        });
+}
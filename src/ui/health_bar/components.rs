@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct HealthBarFill;
+
+#[derive(Component)]
+pub struct HealthBarText;
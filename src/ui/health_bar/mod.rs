@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{states::GameState, system_sets::RunSet};
+
+use self::systems::layout::{spawn_health_bar, update_health_bar};
+
+pub mod components;
+pub mod systems;
+
+pub struct HealthBarPlugin;
+
+impl Plugin for HealthBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Starting),
+            (spawn_health_bar).in_set(RunSet::Setup),
+        )
+        .add_systems(Update, update_health_bar);
+    }
+}
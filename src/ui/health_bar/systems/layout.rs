@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::{
+        components::{Health, MaxHealth, RemoveOnReset},
+        player::components::Player,
+    },
+    ui::{
+        health_bar::components::{HealthBarFill, HealthBarText},
+        widgets::{
+            label::{LabelWidget, TextSize},
+            panel::PanelWidget,
+            resources::Theme,
+        },
+    },
+};
+
+fn fill_style(fraction: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.),
+        width: Val::Percent(fraction.clamp(0., 1.) * 100.),
+        height: Val::Percent(100.),
+        ..default()
+    }
+}
+
+fn health_text(health: &Health, max_health: &MaxHealth) -> String {
+    format!("{}/{}", health.0.max(0), max_health.0)
+}
+
+pub fn spawn_health_bar(mut commands: Commands, theme: Res<Theme>) {
+    PanelWidget::row()
+        .position(PositionType::Absolute)
+        .spawn(&mut commands, &theme)
+        .insert(RemoveOnReset)
+        .with_children(|parent| {
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        width: Val::Px(theme.widget_size.x),
+                        height: Val::Px(theme.widget_size.y),
+                        ..default()
+                    },
+                    background_color: theme.button_bg.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: fill_style(1.),
+                            background_color: theme.danger_color.into(),
+                            ..default()
+                        },
+                        HealthBarFill,
+                    ));
+                    LabelWidget::new("")
+                        .size(TextSize::Small)
+                        .spawn(parent, &theme)
+                        .insert(HealthBarText);
+                });
+        });
+}
+
+pub fn update_health_bar(
+    q_player: Query<
+        (&Health, &MaxHealth),
+        (With<Player>, Or<(Changed<Health>, Changed<MaxHealth>)>),
+    >,
+    mut q_fill: Query<&mut Style, With<HealthBarFill>>,
+    mut q_text: Query<&mut Text, With<HealthBarText>>,
+) {
+    if let Ok((health, max_health)) = q_player.get_single() {
+        q_fill.iter_mut().for_each(|mut style| {
+            *style = fill_style(health.0 as f32 / max_health.0 as f32);
+        });
+        q_text.iter_mut().for_each(|mut text| {
+            text.sections[0].value = health_text(health, max_health);
+        });
+    }
+}
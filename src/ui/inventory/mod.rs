@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{states::GameState, system_sets::RunSet, upgrades::resources::UpgradeStacks};
+
+use self::systems::layout::{spawn_inventory_row, update_inventory_row};
+
+pub mod components;
+pub mod systems;
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Starting),
+            (spawn_inventory_row).in_set(RunSet::Setup),
+        )
+        .add_systems(
+            Update,
+            (update_inventory_row).run_if(resource_changed::<UpgradeStacks>),
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::{
+        components::RemoveOnReset,
+        upgrades::{
+            assets::{UpgradeDefinition, UpgradeRegistry},
+            resources::{UpgradeRegistryHandle, UpgradeStacks},
+            MAX_PASSIVES,
+        },
+    },
+    ui::{
+        inventory::components::InventoryRow,
+        widgets::{
+            label::{LabelWidget, TextSize},
+            panel::PanelWidget,
+            resources::Theme,
+        },
+    },
+};
+
+const SLOT_SIZE: f32 = 40.;
+
+fn owned_items<'a>(
+    registry: Option<&'a UpgradeRegistry>,
+    stacks: &UpgradeStacks,
+) -> Vec<(&'a UpgradeDefinition, u32)> {
+    registry
+        .map(|registry| {
+            registry
+                .upgrades
+                .iter()
+                .filter(|upgrade| upgrade.passive)
+                .filter_map(|upgrade| {
+                    stacks
+                        .0
+                        .get(&upgrade.id)
+                        .filter(|level| **level > 0)
+                        .map(|level| (upgrade, *level))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn build_slots(parent: &mut ChildBuilder, items: &[(&UpgradeDefinition, u32)], theme: &Theme) {
+    (0..MAX_PASSIVES).for_each(|slot| {
+        let background = items
+            .get(slot)
+            .map_or(theme.button_bg, |(item, _)| item.icon_color());
+        parent
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(SLOT_SIZE),
+                    height: Val::Px(SLOT_SIZE),
+                    border: UiRect::all(Val::Px(2.)),
+                    align_items: AlignItems::End,
+                    justify_content: JustifyContent::End,
+                    ..default()
+                },
+                background_color: background.into(),
+                border_color: theme.button_hover_bg.into(),
+                ..default()
+            })
+            .with_children(|parent| {
+                if let Some((_, level)) = items.get(slot) {
+                    LabelWidget::new(level.to_string())
+                        .size(TextSize::Small)
+                        .color(Color::BLACK)
+                        .spawn(parent, theme);
+                }
+            });
+    });
+}
+
+pub fn spawn_inventory_row(
+    mut commands: Commands,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    stacks: Res<UpgradeStacks>,
+    theme: Res<Theme>,
+) {
+    let items = owned_items(registries.get(&registry_handle.0), &stacks);
+    PanelWidget::overlay()
+        .justify(JustifyContent::End)
+        .spawn(&mut commands, &theme)
+        .insert(RemoveOnReset)
+        .with_children(|parent| {
+            PanelWidget::row()
+                .spawn(parent, &theme)
+                .insert(InventoryRow)
+                .with_children(|parent| build_slots(parent, &items, &theme));
+        });
+}
+
+pub fn update_inventory_row(
+    mut commands: Commands,
+    q_row: Query<Entity, With<InventoryRow>>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    stacks: Res<UpgradeStacks>,
+    theme: Res<Theme>,
+) {
+    let items = owned_items(registries.get(&registry_handle.0), &stacks);
+    q_row.iter().for_each(|row| {
+        commands
+            .entity(row)
+            .despawn_descendants()
+            .with_children(|parent| build_slots(parent, &items, &theme));
+    });
+}
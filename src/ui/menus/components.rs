@@ -1,8 +1,5 @@
 use bevy::prelude::*;
 
-#[derive(Component)]
-pub struct StyledButton;
-
 #[derive(Component)]
 pub struct ResumeButton;
 
@@ -11,3 +8,18 @@ pub struct QuitButton;
 
 #[derive(Component)]
 pub struct ResetButton;
+
+#[derive(Component)]
+pub struct PlayButton;
+
+#[derive(Component)]
+pub struct SettingsButton;
+
+#[derive(Component)]
+pub struct StatsButton;
+
+#[derive(Component)]
+pub struct BackButton;
+
+#[derive(Component)]
+pub struct MainMenuButton;
@@ -1,12 +1,21 @@
 use bevy::prelude::*;
 
-use super::systems::layout::UpgradeVariant;
+use crate::gameplay::upgrades::assets::UpgradeId;
 
 #[derive(Component)]
 pub struct UpgradeMenu;
 
 #[derive(Component)]
-pub struct UpgradeVariantComponent(pub UpgradeVariant);
+pub struct UpgradeChoice(pub UpgradeId);
 
 #[derive(Component)]
 pub struct UpgradeButton;
+
+#[derive(Component)]
+pub struct RerollButton;
+
+#[derive(Component)]
+pub struct BanishButton;
+
+#[derive(Component)]
+pub struct SkipButton;
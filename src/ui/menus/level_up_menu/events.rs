@@ -1,8 +1,5 @@
 use bevy::prelude::*;
 
-use super::systems::layout::UpgradeVariant;
-
+/// Rebuilds the level-up menu after the offer or the charges change.
 #[derive(Event)]
-pub struct UpgradeStatEvent {
-    pub variant: UpgradeVariant,
-}
+pub struct RefreshUpgradeMenu;
@@ -4,28 +4,51 @@ use crate::gameplay::states::GameState;
 
 use self::{
     components::UpgradeMenu,
-    events::UpgradeStatEvent,
-    systems::{interact_upgrade_button, layout::spawn_upgrade_menu, process_upgrade_event},
+    events::RefreshUpgradeMenu,
+    resources::BanishArmed,
+    systems::{
+        interact_upgrade_button,
+        interactions::{
+            interact_banish_button, interact_banish_choice, interact_reroll_button,
+            interact_skip_button,
+        },
+        layout::{roll_upgrade_offer, spawn_upgrade_menu},
+    },
 };
 
 use super::despawn_menu;
 
-pub mod bundles;
 pub mod components;
 pub mod events;
-pub mod styles;
+pub mod resources;
 pub mod systems;
 
 pub struct UpgradeMenuPlugin;
 
 impl Plugin for UpgradeMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<UpgradeStatEvent>()
-            .add_systems(OnEnter(GameState::Upgrading), spawn_upgrade_menu)
+        app.init_resource::<BanishArmed>()
+            .add_event::<RefreshUpgradeMenu>()
+            .add_systems(
+                OnEnter(GameState::Upgrading),
+                (roll_upgrade_offer, spawn_upgrade_menu).chain(),
+            )
             .add_systems(OnExit(GameState::Upgrading), despawn_menu::<UpgradeMenu>)
             .add_systems(
                 Update,
-                (interact_upgrade_button, process_upgrade_event)
+                (
+                    (
+                        (interact_upgrade_button)
+                            .run_if(|banish_armed: Res<BanishArmed>| !banish_armed.0),
+                        (interact_banish_choice)
+                            .run_if(|banish_armed: Res<BanishArmed>| banish_armed.0),
+                        interact_reroll_button,
+                        interact_banish_button,
+                        interact_skip_button,
+                    ),
+                    (spawn_upgrade_menu).run_if(on_event::<RefreshUpgradeMenu>()),
+                )
+                    .chain()
                     .run_if(in_state(GameState::Upgrading)),
             );
     }
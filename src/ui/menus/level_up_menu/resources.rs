@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Set while the player is choosing which offered upgrade to banish.
+#[derive(Resource, Default)]
+pub struct BanishArmed(pub bool);
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use rand::thread_rng;
+
+use crate::{
+    gameplay::{
+        components::Luck,
+        player::components::Player,
+        states::GameState,
+        upgrades::{
+            assets::UpgradeRegistry,
+            resources::{
+                BanishedUpgrades, UpgradeCharges, UpgradeOffer, UpgradeRegistryHandle,
+                UpgradeStacks,
+            },
+        },
+    },
+    ui::{
+        menus::level_up_menu::{
+            components::{BanishButton, RerollButton, SkipButton, UpgradeButton, UpgradeChoice},
+            events::RefreshUpgradeMenu,
+            resources::BanishArmed,
+        },
+        score::resources::PlayerScore,
+    },
+};
+
+use super::player_luck;
+
+pub const SKIP_SCORE: u32 = 25;
+
+pub fn interact_reroll_button(
+    button_query: Query<&Interaction, (With<RerollButton>, Changed<Interaction>)>,
+    mut charges: ResMut<UpgradeCharges>,
+    mut offer: ResMut<UpgradeOffer>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    stacks: Res<UpgradeStacks>,
+    banished: Res<BanishedUpgrades>,
+    q_luck: Query<&Luck, With<Player>>,
+    mut ev_refresh: EventWriter<RefreshUpgradeMenu>,
+) {
+    let Some(registry) = registries.get(&registry_handle.0) else {
+        return;
+    };
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        if charges.rerolls > 0 {
+            charges.rerolls -= 1;
+            offer.reroll(
+                registry,
+                &stacks,
+                &banished,
+                player_luck(&q_luck),
+                &mut thread_rng(),
+            );
+            ev_refresh.send(RefreshUpgradeMenu);
+        }
+    }
+}
+
+pub fn interact_banish_button(
+    button_query: Query<&Interaction, (With<BanishButton>, Changed<Interaction>)>,
+    charges: Res<UpgradeCharges>,
+    mut banish_armed: ResMut<BanishArmed>,
+    mut ev_refresh: EventWriter<RefreshUpgradeMenu>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        if banish_armed.0 || charges.banishes > 0 {
+            banish_armed.0 = !banish_armed.0;
+            ev_refresh.send(RefreshUpgradeMenu);
+        }
+    }
+}
+
+pub fn interact_banish_choice(
+    button_query: Query<
+        (&Interaction, &UpgradeChoice),
+        (With<UpgradeButton>, Changed<Interaction>),
+    >,
+    mut banish_armed: ResMut<BanishArmed>,
+    mut charges: ResMut<UpgradeCharges>,
+    mut offer: ResMut<UpgradeOffer>,
+    mut banished: ResMut<BanishedUpgrades>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    stacks: Res<UpgradeStacks>,
+    q_luck: Query<&Luck, With<Player>>,
+    mut ev_refresh: EventWriter<RefreshUpgradeMenu>,
+) {
+    let Some(registry) = registries.get(&registry_handle.0) else {
+        return;
+    };
+    if let Some((_, choice)) = button_query
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        charges.banishes = charges.banishes.saturating_sub(1);
+        banish_armed.0 = false;
+        offer.0.retain(|id| *id != choice.0);
+        banished.0.insert(choice.0.clone());
+        offer.fill(
+            registry,
+            &stacks,
+            &banished,
+            player_luck(&q_luck),
+            &mut thread_rng(),
+        );
+        ev_refresh.send(RefreshUpgradeMenu);
+    }
+}
+
+pub fn interact_skip_button(
+    button_query: Query<&Interaction, (With<SkipButton>, Changed<Interaction>)>,
+    mut charges: ResMut<UpgradeCharges>,
+    mut score: ResMut<PlayerScore>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        if charges.skips > 0 {
+            charges.skips -= 1;
+            score.0 += SKIP_SCORE;
+            next_state.set(GameState::Running);
+        }
+    }
+}
@@ -1,52 +1,246 @@
 use bevy::prelude::*;
 
-use crate::ui::menus::level_up_menu::{
-    bundles::UpgradeButtonBundle, components::UpgradeMenu, styles::UpgradeMenuStyle,
+use crate::{
+    gameplay::{
+        components::Luck,
+        pickups::resources::PendingChests,
+        player::components::Player,
+        states::GameState,
+        stats::components::{ModifierSource, Stat, StatModifier, Stats},
+        upgrades::{
+            assets::{UpgradeDefinition, UpgradeRegistry},
+            resources::{
+                BanishedUpgrades, UpgradeCharges, UpgradeOffer, UpgradeRegistryHandle,
+                UpgradeStacks,
+            },
+        },
+        weapons::components::WeaponKind,
+    },
+    ui::{
+        menus::level_up_menu::{
+            components::{
+                BanishButton, RerollButton, SkipButton, UpgradeButton, UpgradeChoice, UpgradeMenu,
+            },
+            events::RefreshUpgradeMenu,
+            resources::BanishArmed,
+        },
+        widgets::{
+            button::ButtonWidget,
+            card::CardWidget,
+            label::{LabelWidget, TextSize},
+            panel::PanelWidget,
+            resources::Theme,
+        },
+    },
 };
 
-use rand::{seq::IteratorRandom, thread_rng};
+use super::{interactions::SKIP_SCORE, player_luck};
+
+use rand::thread_rng;
 
-#[derive(Clone, Copy, Debug)]
-pub enum UpgradeVariant {
-    Speed,
-    Damage,
-    Health,
-    FireRate,
-    ShotSpeed,
+pub fn roll_upgrade_offer(
+    mut offer: ResMut<UpgradeOffer>,
+    mut banish_armed: ResMut<BanishArmed>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    stacks: Res<UpgradeStacks>,
+    banished: Res<BanishedUpgrades>,
+    mut chests: ResMut<PendingChests>,
+    q_luck: Query<&Luck, With<Player>>,
+) {
+    offer.0.clear();
+    banish_armed.0 = false;
+    if let Some(registry) = registries.get(&registry_handle.0) {
+        // A chest's evolution shows up even if it was banished earlier
+        if chests.0 > 0 {
+            chests.0 -= 1;
+            if let Some(evolution) = registry.ready_evolutions(&stacks.0).first() {
+                offer.0.push(evolution.id.clone());
+            }
+        }
+        offer.fill(
+            registry,
+            &stacks,
+            &banished,
+            player_luck(&q_luck),
+            &mut thread_rng(),
+        );
+    }
 }
 
-pub fn spawn_upgrade_menu(mut commands: Commands) {
-    let mut rng = thread_rng();
-    let upgrade_variants = [
-        UpgradeVariant::Speed,
-        UpgradeVariant::Damage,
-        UpgradeVariant::Health,
-        UpgradeVariant::FireRate,
-        UpgradeVariant::ShotSpeed,
-    ]
-    .iter()
-    .choose_multiple(&mut rng, 3);
-    build_upgrade_menu(&mut commands, &upgrade_variants);
+pub fn spawn_upgrade_menu(
+    mut commands: Commands,
+    q_menu: Query<Entity, With<UpgradeMenu>>,
+    mut ev_refresh: EventReader<RefreshUpgradeMenu>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    offer: Res<UpgradeOffer>,
+    stacks: Res<UpgradeStacks>,
+    charges: Res<UpgradeCharges>,
+    banish_armed: Res<BanishArmed>,
+    player_query: Query<&Stats, With<Player>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    theme: Res<Theme>,
+) {
+    ev_refresh.clear();
+    q_menu
+        .iter()
+        .for_each(|menu| commands.entity(menu).despawn_recursive());
+    let Some(registry) = registries.get(&registry_handle.0) else {
+        next_state.set(GameState::Running);
+        return;
+    };
+    let choices: Vec<&UpgradeDefinition> =
+        offer.0.iter().filter_map(|id| registry.get(id)).collect();
+    if choices.is_empty() {
+        next_state.set(GameState::Running);
+        return;
+    }
+    build_upgrade_menu(
+        &mut commands,
+        registry,
+        &choices,
+        &stacks,
+        &charges,
+        banish_armed.0,
+        player_query.get_single().ok(),
+        &theme,
+    );
 }
 
-fn build_upgrade_menu(commands: &mut Commands, variants: &Vec<&UpgradeVariant>) -> Entity {
-    commands
-        .spawn((
-            NodeBundle {
-                style: UpgradeMenuStyle::default().0,
-                background_color: UpgradeMenuStyle::bg_color().into(),
-                ..default()
-            },
-            UpgradeMenu,
-        ))
+fn stat_changes(upgrade: &UpgradeDefinition, stats: &Stats) -> Vec<String> {
+    let mut preview = stats.clone();
+    let mut changed: Vec<Stat> = Vec::new();
+    upgrade.modifiers.iter().for_each(|modifier| {
+        preview.add_modifier(StatModifier::permanent(
+            modifier.stat,
+            modifier.kind,
+            modifier.value,
+            ModifierSource::Upgrade(upgrade.id.clone()),
+        ));
+        if !changed.contains(&modifier.stat) {
+            changed.push(modifier.stat);
+        }
+    });
+    changed
+        .into_iter()
+        .map(|stat| {
+            format!(
+                "{} {} -> {}",
+                stat.label(),
+                stat.format(stats.value(stat)),
+                stat.format(preview.value(stat))
+            )
+        })
+        .collect()
+}
+
+fn weapon_changes(kind: WeaponKind, level: u32) -> Vec<String> {
+    let after = kind.stats(level + 1);
+    if level == 0 {
+        return vec![
+            "New weapon".to_string(),
+            format!("Damage {}, every {:.2}s", after.damage, after.cooldown),
+        ];
+    }
+    let before = kind.stats(level);
+    let mut changes = vec![format!("Damage {} -> {}", before.damage, after.damage)];
+    if before.cooldown != after.cooldown {
+        changes.push(format!(
+            "Cooldown {:.2}s -> {:.2}s",
+            before.cooldown, after.cooldown
+        ));
+    }
+    if before.count != after.count {
+        changes.push(format!("Amount {} -> {}", before.count, after.count));
+    }
+    if before.area != after.area {
+        changes.push(format!("Area {:.0} -> {:.0}", before.area, after.area));
+    }
+    changes
+}
+
+fn build_upgrade_menu(
+    commands: &mut Commands,
+    registry: &UpgradeRegistry,
+    choices: &[&UpgradeDefinition],
+    stacks: &UpgradeStacks,
+    charges: &UpgradeCharges,
+    banish_armed: bool,
+    player: Option<&Stats>,
+    theme: &Theme,
+) -> Entity {
+    let action_size = Vec2::new(theme.button_size.x, theme.widget_size.y * 1.5);
+    PanelWidget::overlay()
+        .gap(theme.gap * 3.)
+        .spawn(commands, theme)
+        .insert(UpgradeMenu)
         .with_children(|parent| {
-            variants.iter().for_each(|var| {
-                parent
-                    .spawn(UpgradeButtonBundle::with_variant(**var))
-                    .with_children(|bp| {
-                        bp.spawn(UpgradeButtonBundle::get_text(&var));
+            PanelWidget::row()
+                .gap(theme.gap * 2.)
+                .spawn(parent, theme)
+                .with_children(|parent| {
+                    choices.iter().for_each(|upgrade| {
+                        let stack_count = stacks.0.get(&upgrade.id).copied().unwrap_or(0);
+                        let card = CardWidget::new(upgrade.name.clone())
+                            .icon(upgrade.icon_color())
+                            .border(theme.rarity_color(upgrade.rarity))
+                            .line(upgrade.description.clone());
+                        let card = match registry
+                            .evolution_for(&upgrade.id)
+                            .and_then(|recipe| registry.get(&recipe.weapon))
+                        {
+                            Some(base) => card.line(format!("Evolves {}", base.name)),
+                            None => {
+                                card.line(format!("Level {}/{}", stack_count, upgrade.max_stacks))
+                            }
+                        };
+                        let card = player
+                            .map(|stats| stat_changes(upgrade, stats))
+                            .unwrap_or_default()
+                            .into_iter()
+                            .chain(
+                                upgrade
+                                    .weapon
+                                    .filter(|_| registry.evolution_for(&upgrade.id).is_none())
+                                    .map(|kind| weapon_changes(kind, stack_count))
+                                    .unwrap_or_default(),
+                            )
+                            .fold(card, |card, change| card.line(change));
+                        card.spawn(parent, theme)
+                            .insert((UpgradeChoice(upgrade.id.clone()), UpgradeButton));
                     });
-            })
+                });
+            if banish_armed {
+                LabelWidget::new("Pick an upgrade to banish")
+                    .color(theme.danger_color)
+                    .spawn(parent, theme);
+            }
+            PanelWidget::row()
+                .gap(theme.gap * 2.)
+                .spawn(parent, theme)
+                .with_children(|parent| {
+                    ButtonWidget::new(format!("Reroll ({})", charges.rerolls))
+                        .size(action_size)
+                        .text_size(TextSize::Small)
+                        .spawn(parent, theme)
+                        .insert(RerollButton);
+                    let banish_label = if banish_armed {
+                        "Cancel banish".to_string()
+                    } else {
+                        format!("Banish ({})", charges.banishes)
+                    };
+                    ButtonWidget::new(banish_label)
+                        .size(action_size)
+                        .text_size(TextSize::Small)
+                        .spawn(parent, theme)
+                        .insert(BanishButton);
+                    ButtonWidget::new(format!("Skip +{} ({})", SKIP_SCORE, charges.skips))
+                        .size(action_size)
+                        .text_size(TextSize::Small)
+                        .spawn(parent, theme)
+                        .insert(SkipButton);
+                });
         })
         .id()
 }
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+use self::{components::MainMenu, systems::layout::spawn_main_menu};
+
+use super::despawn_menu;
+
+pub mod components;
+pub mod systems;
+
+pub struct MainMenuPlugin;
+
+impl Plugin for MainMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::MainMenu), spawn_main_menu)
+            .add_systems(OnExit(GameState::MainMenu), despawn_menu::<MainMenu>);
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::ui::{
+    menus::{
+        components::{PlayButton, QuitButton, SettingsButton, StatsButton},
+        main_menu::components::MainMenu,
+    },
+    widgets::{
+        button::ButtonWidget,
+        label::{LabelWidget, TextSize},
+        panel::PanelWidget,
+        resources::Theme,
+    },
+};
+
+fn build_main_menu(commands: &mut Commands, theme: &Theme) -> Entity {
+    PanelWidget::overlay()
+        .spawn(commands, theme)
+        .insert(MainMenu)
+        .with_children(|parent| {
+            LabelWidget::new("Cubes Attack")
+                .size(TextSize::Title)
+                .spawn(parent, theme);
+            ButtonWidget::new("Play")
+                .spawn(parent, theme)
+                .insert(PlayButton);
+            ButtonWidget::new("Settings")
+                .spawn(parent, theme)
+                .insert(SettingsButton);
+            ButtonWidget::new("Stats")
+                .spawn(parent, theme)
+                .insert(StatsButton);
+            ButtonWidget::new("Quit")
+                .spawn(parent, theme)
+                .insert(QuitButton);
+        })
+        .id()
+}
+
+pub fn spawn_main_menu(mut commands: Commands, theme: Res<Theme>) {
+    build_main_menu(&mut commands, &theme);
+}
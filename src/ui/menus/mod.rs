@@ -4,18 +4,26 @@ use crate::gameplay::states::GameState;
 
 use self::{
     game_over_menu::GameOverPlugin,
+    main_menu::MainMenuPlugin,
     pause_menu::PauseMenuPlugin,
+    resources::MenuReturnState,
+    settings_menu::SettingsMenuPlugin,
+    stats_menu::StatsMenuPlugin,
     systems::interactions::{
-        interact_styled_button, interact_with_quit_button, interact_with_reset_button,
-        interact_with_resume_button,
+        back_on_escape, interact_with_back_button, interact_with_main_menu_button,
+        interact_with_play_button, interact_with_quit_button, interact_with_reset_button,
+        interact_with_resume_button, interact_with_settings_button, interact_with_stats_button,
     },
 };
 
 pub mod components;
 pub mod game_over_menu;
 pub mod level_up_menu;
+pub mod main_menu;
 pub mod pause_menu;
-pub mod styles;
+pub mod resources;
+pub mod settings_menu;
+pub mod stats_menu;
 mod systems;
 
 pub fn despawn_menu<T: Component>(mut commands: Commands, q_pause_menu: Query<Entity, With<T>>) {
@@ -28,16 +36,32 @@ pub struct GlobalMenuPlugin;
 
 impl Plugin for GlobalMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((GameOverPlugin, PauseMenuPlugin))
-            .add_systems(
-                Update,
-                (
-                    interact_styled_button,
-                    interact_with_quit_button,
-                    interact_with_reset_button,
-                    interact_with_resume_button,
-                )
-                    .run_if(not(in_state(GameState::Running))),
-            );
+        app.add_plugins((
+            GameOverPlugin,
+            PauseMenuPlugin,
+            MainMenuPlugin,
+            SettingsMenuPlugin,
+            StatsMenuPlugin,
+        ))
+        .init_resource::<MenuReturnState>()
+        .add_systems(
+            Update,
+            (
+                interact_with_quit_button,
+                interact_with_reset_button,
+                interact_with_resume_button,
+                interact_with_play_button,
+                interact_with_settings_button,
+                interact_with_stats_button,
+                interact_with_main_menu_button,
+                interact_with_back_button,
+            )
+                .run_if(not(in_state(GameState::Running))),
+        )
+        .add_systems(
+            Update,
+            (back_on_escape)
+                .run_if(in_state(GameState::Settings).or_else(in_state(GameState::Stats))),
+        );
     }
 }
@@ -1,65 +1,37 @@
 use bevy::prelude::*;
 
-use crate::ui::menus::{
-    components::{QuitButton, ResetButton, ResumeButton, StyledButton},
-    pause_menu::components::PauseMenu,
-    styles::{ButtonStyle, MenuStyle},
+use crate::ui::{
+    menus::{
+        components::{MainMenuButton, QuitButton, ResetButton, ResumeButton, SettingsButton},
+        pause_menu::components::PauseMenu,
+    },
+    widgets::{button::ButtonWidget, panel::PanelWidget, resources::Theme},
 };
 
-fn buld_pause_menu(commands: &mut Commands) -> Entity {
-    commands
-        .spawn((
-            NodeBundle {
-                style: MenuStyle::default().0,
-                background_color: MenuStyle::bg_color().into(),
-                ..default()
-            },
-            PauseMenu,
-        ))
+fn build_pause_menu(commands: &mut Commands, theme: &Theme) -> Entity {
+    PanelWidget::overlay()
+        .spawn(commands, theme)
+        .insert(PauseMenu)
         .with_children(|parent| {
-            parent
-                .spawn((
-                    ButtonBundle {
-                        style: ButtonStyle::default().0,
-                        background_color: ButtonStyle::bg_color().into(),
-                        ..default()
-                    },
-                    ResumeButton,
-                    StyledButton,
-                ))
-                .with_children(|parent| {
-                    parent.spawn(ButtonStyle::text("Resume"));
-                });
-            parent
-                .spawn((
-                    ButtonBundle {
-                        style: ButtonStyle::default().0,
-                        background_color: ButtonStyle::bg_color().into(),
-                        ..default()
-                    },
-                    ResetButton,
-                    StyledButton,
-                ))
-                .with_children(|parent| {
-                    parent.spawn(ButtonStyle::text("Reset"));
-                });
-            parent
-                .spawn((
-                    ButtonBundle {
-                        style: ButtonStyle::default().0,
-                        background_color: ButtonStyle::bg_color().into(),
-                        ..default()
-                    },
-                    QuitButton,
-                    StyledButton,
-                ))
-                .with_children(|parent| {
-                    parent.spawn(ButtonStyle::text("Quit"));
-                });
+            ButtonWidget::new("Resume")
+                .spawn(parent, theme)
+                .insert(ResumeButton);
+            ButtonWidget::new("Reset")
+                .spawn(parent, theme)
+                .insert(ResetButton);
+            ButtonWidget::new("Settings")
+                .spawn(parent, theme)
+                .insert(SettingsButton);
+            ButtonWidget::new("Main menu")
+                .spawn(parent, theme)
+                .insert(MainMenuButton);
+            ButtonWidget::new("Quit")
+                .spawn(parent, theme)
+                .insert(QuitButton);
         })
         .id()
 }
 
-pub fn spawn_pause_menu(mut commands: Commands) {
-    buld_pause_menu(&mut commands);
+pub fn spawn_pause_menu(mut commands: Commands, theme: Res<Theme>) {
+    build_pause_menu(&mut commands, &theme);
 }
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+#[derive(Resource)]
+pub struct MenuReturnState(pub GameState);
+
+impl Default for MenuReturnState {
+    fn default() -> Self {
+        MenuReturnState(GameState::MainMenu)
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct SettingsMenu;
+
+#[derive(Component, Clone, Copy)]
+pub enum SettingField {
+    WindowMode,
+    Resolution,
+    Vsync,
+    UiScale,
+    ScreenShake,
+    DamageNumbers,
+    AutoFire,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+}
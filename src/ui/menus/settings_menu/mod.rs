@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::gameplay::states::GameState;
+
+use self::{
+    components::SettingsMenu,
+    systems::{
+        interactions::{interact_with_cycle_setting, sync_slider_settings, sync_toggle_settings},
+        layout::spawn_settings_menu,
+    },
+};
+
+use super::despawn_menu;
+
+pub mod components;
+pub mod systems;
+
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Settings), spawn_settings_menu)
+            .add_systems(OnExit(GameState::Settings), despawn_menu::<SettingsMenu>)
+            .add_systems(
+                Update,
+                (
+                    interact_with_cycle_setting,
+                    sync_slider_settings,
+                    sync_toggle_settings,
+                )
+                    .run_if(in_state(GameState::Settings)),
+            );
+    }
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{
+    settings::resources::Settings,
+    ui::{
+        menus::settings_menu::components::SettingField,
+        widgets::components::{Slider, Toggle},
+    },
+};
+
+pub fn interact_with_cycle_setting(
+    button_query: Query<(&Interaction, &SettingField, &Children), Changed<Interaction>>,
+    mut text_query: Query<&mut Text>,
+    mut settings: ResMut<Settings>,
+) {
+    button_query
+        .iter()
+        .filter(|(interaction, _, _)| **interaction == Interaction::Pressed)
+        .for_each(|(_, field, children)| {
+            let label = match field {
+                SettingField::WindowMode => {
+                    settings.window_mode = settings.window_mode.next();
+                    settings.window_mode.label().to_string()
+                }
+                SettingField::Resolution => {
+                    settings.resolution = settings.next_resolution();
+                    settings.resolution_label()
+                }
+                _ => return,
+            };
+            children.iter().for_each(|child| {
+                if let Ok(mut text) = text_query.get_mut(*child) {
+                    text.sections[0].value = label.clone();
+                }
+            });
+        });
+}
+
+pub fn sync_slider_settings(
+    slider_query: Query<(&Slider, &SettingField), Changed<Slider>>,
+    mut settings: ResMut<Settings>,
+) {
+    slider_query.iter().for_each(|(slider, field)| match field {
+        SettingField::UiScale => settings.ui_scale = slider.value,
+        SettingField::ScreenShake => settings.screen_shake = slider.value,
+        SettingField::MasterVolume => settings.master_volume = slider.value,
+        SettingField::MusicVolume => settings.music_volume = slider.value,
+        SettingField::SfxVolume => settings.sfx_volume = slider.value,
+        _ => {}
+    });
+}
+
+pub fn sync_toggle_settings(
+    toggle_query: Query<(&Toggle, &SettingField), Changed<Toggle>>,
+    mut settings: ResMut<Settings>,
+) {
+    toggle_query.iter().for_each(|(toggle, field)| match field {
+        SettingField::Vsync => settings.vsync = toggle.0,
+        SettingField::DamageNumbers => settings.damage_numbers = toggle.0,
+        SettingField::AutoFire => settings.auto_fire = toggle.0,
+        _ => {}
+    });
+}
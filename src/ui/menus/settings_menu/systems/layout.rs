@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use crate::{
+    settings::resources::Settings,
+    ui::{
+        menus::{
+            components::BackButton,
+            settings_menu::components::{SettingField, SettingsMenu},
+        },
+        widgets::{
+            button::ButtonWidget,
+            label::{LabelWidget, TextSize},
+            panel::PanelWidget,
+            resources::Theme,
+            slider::SliderWidget,
+            toggle::ToggleWidget,
+            UiParent,
+        },
+    },
+};
+
+fn spawn_row(
+    parent: &mut impl UiParent,
+    theme: &Theme,
+    label: &str,
+    widget: impl FnOnce(&mut ChildBuilder),
+) {
+    PanelWidget::row()
+        .gap(16.)
+        .spawn(parent, theme)
+        .with_children(|row| {
+            LabelWidget::new(label)
+                .size(TextSize::Small)
+                .width(Val::Px(220.))
+                .spawn(row, theme);
+            widget(row);
+        });
+}
+
+fn build_settings_menu(commands: &mut Commands, settings: &Settings, theme: &Theme) -> Entity {
+    let cycle_size = theme.widget_size;
+    PanelWidget::overlay()
+        .background(theme.panel_bg)
+        .spawn(commands, theme)
+        .insert(SettingsMenu)
+        .with_children(|parent| {
+            LabelWidget::new("Settings")
+                .size(TextSize::Heading)
+                .spawn(parent, theme);
+            spawn_row(parent, theme, "Window mode", |row| {
+                ButtonWidget::new(settings.window_mode.label())
+                    .size(cycle_size)
+                    .text_size(TextSize::Small)
+                    .spawn(row, theme)
+                    .insert(SettingField::WindowMode);
+            });
+            spawn_row(parent, theme, "Resolution", |row| {
+                ButtonWidget::new(settings.resolution_label())
+                    .size(cycle_size)
+                    .text_size(TextSize::Small)
+                    .spawn(row, theme)
+                    .insert(SettingField::Resolution);
+            });
+            spawn_row(parent, theme, "VSync", |row| {
+                ToggleWidget::new(settings.vsync)
+                    .spawn(row, theme)
+                    .insert(SettingField::Vsync);
+            });
+            spawn_row(parent, theme, "UI scale", |row| {
+                SliderWidget::new(settings.ui_scale, 0.5, 2.)
+                    .step(0.25)
+                    .spawn(row, theme)
+                    .insert(SettingField::UiScale);
+            });
+            spawn_row(parent, theme, "Screen shake", |row| {
+                SliderWidget::new(settings.screen_shake, 0., 1.)
+                    .step(0.1)
+                    .spawn(row, theme)
+                    .insert(SettingField::ScreenShake);
+            });
+            spawn_row(parent, theme, "Damage numbers", |row| {
+                ToggleWidget::new(settings.damage_numbers)
+                    .spawn(row, theme)
+                    .insert(SettingField::DamageNumbers);
+            });
+            spawn_row(parent, theme, "Auto-fire", |row| {
+                ToggleWidget::new(settings.auto_fire)
+                    .spawn(row, theme)
+                    .insert(SettingField::AutoFire);
+            });
+            [
+                (
+                    "Master volume",
+                    SettingField::MasterVolume,
+                    settings.master_volume,
+                ),
+                (
+                    "Music volume",
+                    SettingField::MusicVolume,
+                    settings.music_volume,
+                ),
+                ("SFX volume", SettingField::SfxVolume, settings.sfx_volume),
+            ]
+            .into_iter()
+            .for_each(|(label, field, value)| {
+                spawn_row(parent, theme, label, |row| {
+                    SliderWidget::new(value, 0., 1.)
+                        .step(0.05)
+                        .spawn(row, theme)
+                        .insert(field);
+                });
+            });
+            ButtonWidget::new("Back")
+                .spawn(parent, theme)
+                .insert(BackButton);
+        })
+        .id()
+}
+
+pub fn spawn_settings_menu(mut commands: Commands, settings: Res<Settings>, theme: Res<Theme>) {
+    build_settings_menu(&mut commands, &settings, &theme);
+}
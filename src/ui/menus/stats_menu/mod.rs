@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{states::GameState, system_sets::RunSet};
+
+use self::{
+    components::StatsMenu,
+    resources::LifetimeStats,
+    systems::{
+        layout::spawn_stats_menu,
+        updates::{record_item_pickups, record_run_end, record_run_start, save_lifetime_stats},
+    },
+};
+
+use super::despawn_menu;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+pub struct StatsMenuPlugin;
+
+impl Plugin for StatsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LifetimeStats::load())
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (record_run_start, save_lifetime_stats)
+                    .chain()
+                    .in_set(RunSet::Setup),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (record_run_end, save_lifetime_stats).chain(),
+            )
+            .add_systems(Update, record_item_pickups)
+            .add_systems(OnEnter(GameState::Stats), spawn_stats_menu)
+            .add_systems(OnExit(GameState::Stats), despawn_menu::<StatsMenu>);
+    }
+}
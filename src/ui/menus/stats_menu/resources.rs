@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence::{load_ron, save_ron};
+
+#[derive(Resource, Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub runs_played: u32,
+    pub best_score: u32,
+    pub total_score: u32,
+    pub items_collected: u32,
+}
+
+impl LifetimeStats {
+    const FILE: &'static str = "stats.ron";
+
+    pub fn load() -> Self {
+        load_ron(Self::FILE)
+    }
+
+    pub fn save(&self) {
+        save_ron(Self::FILE, self);
+    }
+}
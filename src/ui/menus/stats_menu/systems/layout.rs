@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use crate::ui::{
+    menus::{
+        components::BackButton,
+        stats_menu::{components::StatsMenu, resources::LifetimeStats},
+    },
+    widgets::{
+        button::ButtonWidget,
+        label::{LabelWidget, TextSize},
+        list::ListWidget,
+        panel::PanelWidget,
+        resources::Theme,
+    },
+};
+
+fn build_stats_menu(commands: &mut Commands, stats: &LifetimeStats, theme: &Theme) -> Entity {
+    PanelWidget::overlay()
+        .spawn(commands, theme)
+        .insert(StatsMenu)
+        .with_children(|parent| {
+            LabelWidget::new("Stats")
+                .size(TextSize::Title)
+                .spawn(parent, theme);
+            ListWidget::new([
+                format!("Runs played: {runs}", runs = stats.runs_played),
+                format!("Best score: {best}", best = stats.best_score),
+                format!("Total score: {total}", total = stats.total_score),
+                format!("Items collected: {items}", items = stats.items_collected),
+            ])
+            .spawn(parent, theme);
+            ButtonWidget::new("Back")
+                .spawn(parent, theme)
+                .insert(BackButton);
+        })
+        .id()
+}
+
+pub fn spawn_stats_menu(mut commands: Commands, stats: Res<LifetimeStats>, theme: Res<Theme>) {
+    build_stats_menu(&mut commands, &stats, &theme);
+}
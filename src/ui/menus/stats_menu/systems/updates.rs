@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    gameplay::upgrades::{
+        assets::UpgradeRegistry, events::UpgradeStatEvent, resources::UpgradeRegistryHandle,
+    },
+    ui::{menus::stats_menu::resources::LifetimeStats, score::resources::PlayerScore},
+};
+
+pub fn record_run_start(mut stats: ResMut<LifetimeStats>) {
+    stats.runs_played += 1;
+}
+
+pub fn record_run_end(mut stats: ResMut<LifetimeStats>, score: Res<PlayerScore>) {
+    stats.best_score = stats.best_score.max(score.0);
+    stats.total_score += score.0;
+}
+
+pub fn save_lifetime_stats(stats: Res<LifetimeStats>) {
+    stats.save();
+}
+
+pub fn record_item_pickups(
+    mut ev_upgrade: EventReader<UpgradeStatEvent>,
+    registry_handle: Res<UpgradeRegistryHandle>,
+    registries: Res<Assets<UpgradeRegistry>>,
+    mut stats: ResMut<LifetimeStats>,
+) {
+    let Some(registry) = registries.get(&registry_handle.0) else {
+        return;
+    };
+    stats.items_collected += ev_upgrade
+        .read()
+        .filter(|UpgradeStatEvent { id }| registry.get(id).is_some_and(|upgrade| upgrade.passive))
+        .count() as u32;
+}
@@ -1,41 +1,17 @@
 use bevy::prelude::*;
 
 use crate::{
-    gameplay::{
-        components::RemoveOnReset,
-        player::{bundles::PlayerBundle, resources::PlayerExperience},
-        states::GameState,
-    },
-    ui::{
-        menus::{
-            components::{QuitButton, ResetButton, ResumeButton, StyledButton},
-            styles::ButtonStyle,
+    events::NewRunEvent,
+    gameplay::states::GameState,
+    ui::menus::{
+        components::{
+            BackButton, MainMenuButton, PlayButton, QuitButton, ResetButton, ResumeButton,
+            SettingsButton, StatsButton,
         },
-        score::{resources::PlayerScore, systems::layout::build_score_count},
+        resources::MenuReturnState,
     },
 };
 
-pub fn interact_styled_button(
-    mut button_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<StyledButton>),
-    >,
-) {
-    button_query
-        .iter_mut()
-        .for_each(|(interaction, mut bg_color)| match *interaction {
-            Interaction::Pressed => {
-                *bg_color = ButtonStyle::press_bg_color().into();
-            }
-            Interaction::Hovered => {
-                *bg_color = ButtonStyle::hover_bg_color().into();
-            }
-            Interaction::None => {
-                *bg_color = ButtonStyle::bg_color().into();
-            }
-        })
-}
-
 pub fn interact_with_resume_button(
     button_query: Query<&Interaction, (Changed<Interaction>, With<ResumeButton>)>,
     mut next_paused_state: ResMut<NextState<GameState>>,
@@ -56,21 +32,71 @@ pub fn interact_with_quit_button(
 
 pub fn interact_with_reset_button(
     button_query: Query<&Interaction, (Changed<Interaction>, With<ResetButton>)>,
-    mut next_paused_state: ResMut<NextState<GameState>>,
-    mut remove_query: Query<Entity, With<RemoveOnReset>>,
-    mut score: ResMut<PlayerScore>,
-    mut exp: ResMut<PlayerExperience>,
-    mut commands: Commands,
+    mut ev_new_run: EventWriter<NewRunEvent>,
 ) {
     if let Ok(Interaction::Pressed) = button_query.get_single() {
-        next_paused_state.set(GameState::Running);
-        remove_query.iter_mut().for_each(|ent| {
-            commands.entity(ent).despawn_recursive();
-        });
+        ev_new_run.send(NewRunEvent);
+    }
+}
+
+pub fn interact_with_play_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<PlayButton>)>,
+    mut ev_new_run: EventWriter<NewRunEvent>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        ev_new_run.send(NewRunEvent);
+    }
+}
+
+pub fn interact_with_settings_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<SettingsButton>)>,
+    state: Res<State<GameState>>,
+    mut return_state: ResMut<MenuReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        *return_state = MenuReturnState(state.get().clone());
+        next_state.set(GameState::Settings);
+    }
+}
 
-        *score = PlayerScore(0);
-        *exp = PlayerExperience(0);
-        build_score_count(&mut commands, &Res::from(score));
-        commands.spawn(PlayerBundle::default());
+pub fn interact_with_stats_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<StatsButton>)>,
+    state: Res<State<GameState>>,
+    mut return_state: ResMut<MenuReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        *return_state = MenuReturnState(state.get().clone());
+        next_state.set(GameState::Stats);
+    }
+}
+
+pub fn interact_with_main_menu_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<MainMenuButton>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        next_state.set(GameState::MainMenu);
+    }
+}
+
+pub fn interact_with_back_button(
+    button_query: Query<&Interaction, (Changed<Interaction>, With<BackButton>)>,
+    return_state: Res<MenuReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Ok(Interaction::Pressed) = button_query.get_single() {
+        next_state.set(return_state.0.clone());
+    }
+}
+
+pub fn back_on_escape(
+    keys: Res<ButtonInput<KeyCode>>,
+    return_state: Res<MenuReturnState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(return_state.0.clone());
     }
 }
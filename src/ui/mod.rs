@@ -1,17 +1,36 @@
 use bevy::prelude::*;
 
 use self::{
+    boss_bar::BossBarPlugin,
+    floating_text::FloatingTextPlugin,
+    health_bar::HealthBarPlugin,
+    inventory::InventoryPlugin,
     menus::{level_up_menu::UpgradeMenuPlugin, GlobalMenuPlugin},
     score::ScorePlugin,
+    widgets::WidgetsPlugin,
 };
 
+pub mod boss_bar;
+pub mod floating_text;
+pub mod health_bar;
+pub mod inventory;
 pub mod menus;
 pub mod score;
+pub mod widgets;
 
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((GlobalMenuPlugin, ScorePlugin, UpgradeMenuPlugin));
+        app.add_plugins((
+            GlobalMenuPlugin,
+            ScorePlugin,
+            UpgradeMenuPlugin,
+            FloatingTextPlugin,
+            InventoryPlugin,
+            HealthBarPlugin,
+            BossBarPlugin,
+            WidgetsPlugin,
+        ));
     }
 }
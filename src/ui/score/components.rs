@@ -5,3 +5,6 @@ pub struct ScoreCountNode;
 
 #[derive(Component)]
 pub struct ScoreCountText;
+
+#[derive(Component)]
+pub struct GoldCountText;
@@ -1,13 +1,15 @@
 use bevy::prelude::*;
 
-use crate::gameplay::states::GameState;
+use crate::gameplay::{states::GameState, system_sets::RunSet, systems::clear_events};
 
 use self::{
     events::ScoreUpEvent,
     resources::PlayerScore,
     systems::{
         layout::{despawn_score_count, spawn_score_count},
-        updates::{trigger_score_update, update_score, update_score_text},
+        updates::{
+            reset_score, trigger_score_update, update_gold_text, update_score, update_score_text,
+        },
     },
 };
 
@@ -22,11 +24,22 @@ impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<ScoreUpEvent>()
             .insert_resource(PlayerScore(0))
-            .add_systems(Startup, spawn_score_count)
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (
+                    (reset_score, clear_events::<ScoreUpEvent>).in_set(RunSet::Reset),
+                    (spawn_score_count).in_set(RunSet::Setup),
+                ),
+            )
             .add_systems(OnEnter(GameState::GameOver), despawn_score_count)
             .add_systems(
                 Update,
-                (trigger_score_update, update_score, update_score_text)
+                (
+                    trigger_score_update,
+                    update_score,
+                    update_score_text,
+                    update_gold_text,
+                )
                     .run_if(in_state(GameState::Running)),
             );
     }
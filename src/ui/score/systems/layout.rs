@@ -1,54 +1,58 @@
 use bevy::prelude::*;
 
 use crate::{
-    gameplay::components::RemoveOnReset,
-    ui::score::{
-        components::{ScoreCountNode, ScoreCountText},
-        resources::PlayerScore,
+    gameplay::{components::RemoveOnReset, pickups::resources::PlayerGold},
+    ui::{
+        score::{
+            components::{GoldCountText, ScoreCountNode, ScoreCountText},
+            resources::PlayerScore,
+        },
+        widgets::{label::LabelWidget, panel::PanelWidget, resources::Theme},
     },
 };
 
-pub fn spawn_score_count(mut commands: Commands, player_score: Res<PlayerScore>) {
-    build_score_count(&mut commands, &player_score);
+pub fn spawn_score_count(
+    mut commands: Commands,
+    player_score: Res<PlayerScore>,
+    gold: Res<PlayerGold>,
+    theme: Res<Theme>,
+) {
+    build_score_count(&mut commands, &player_score, &gold, &theme);
 }
 
 pub fn despawn_score_count(
     mut commands: Commands,
-    query_score: Query<Entity, With<ScoreCountText>>,
+    query_score: Query<Entity, Or<(With<ScoreCountText>, With<GoldCountText>)>>,
 ) {
-    if let Ok(entity) = query_score.get_single() {
+    query_score.iter().for_each(|entity| {
         commands.entity(entity).despawn_recursive();
-    }
+    });
 }
 
-pub fn build_score_count(commands: &mut Commands, player_score: &Res<PlayerScore>) -> Entity {
-    commands
-        .spawn((
-            NodeBundle {
-                style: Style {
-                    position_type: PositionType::Absolute,
-                    flex_direction: FlexDirection::Row,
-                    justify_content: JustifyContent::End,
-                    width: Val::Percent(100.),
-                    ..default()
-                },
-                ..default()
-            },
-            ScoreCountNode,
-            RemoveOnReset,
-        ))
+pub fn build_score_count(
+    commands: &mut Commands,
+    player_score: &PlayerScore,
+    gold: &PlayerGold,
+    theme: &Theme,
+) -> Entity {
+    PanelWidget::row()
+        .position(PositionType::Absolute)
+        .justify(JustifyContent::End)
+        .width(Val::Percent(100.))
+        .spawn(commands, theme)
+        .insert((ScoreCountNode, RemoveOnReset))
         .with_children(|parent| {
-            parent.spawn((
-                TextBundle::from_section(
-                    format!("{score}", score = player_score.0),
-                    TextStyle {
-                        font_size: 32.,
-                        color: Color::WHITE,
-                        ..default()
-                    },
-                ),
-                ScoreCountText,
-            ));
+            LabelWidget::new(gold_text(gold))
+                .color(Color::GOLD)
+                .spawn(parent, theme)
+                .insert(GoldCountText);
+            LabelWidget::new(format!("{score}", score = player_score.0))
+                .spawn(parent, theme)
+                .insert(ScoreCountText);
         })
         .id()
 }
+
+pub fn gold_text(gold: &PlayerGold) -> String {
+    format!("{gold}g", gold = gold.0)
+}
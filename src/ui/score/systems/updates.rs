@@ -1,7 +1,16 @@
 use crate::{
-    gameplay::components::{Health, PointWorth},
-    ui::score::{components::ScoreCountText, events::ScoreUpEvent, resources::PlayerScore},
+    gameplay::{
+        components::{Health, PointWorth},
+        pickups::resources::PlayerGold,
+    },
+    ui::score::{
+        components::{GoldCountText, ScoreCountText},
+        events::ScoreUpEvent,
+        resources::PlayerScore,
+    },
 };
+
+use super::layout::gold_text;
 use bevy::prelude::*;
 
 pub fn trigger_score_update(
@@ -16,6 +25,10 @@ pub fn trigger_score_update(
         });
 }
 
+pub fn reset_score(mut score: ResMut<PlayerScore>) {
+    *score = PlayerScore(0);
+}
+
 pub fn update_score(mut ev_score_up: EventReader<ScoreUpEvent>, mut score: ResMut<PlayerScore>) {
     ev_score_up
         .read()
@@ -32,3 +45,11 @@ pub fn update_score_text(
         }
     }
 }
+
+pub fn update_gold_text(mut q_gold: Query<&mut Text, With<GoldCountText>>, gold: Res<PlayerGold>) {
+    if gold.is_changed() {
+        if let Ok(mut text) = q_gold.get_single_mut() {
+            text.sections[0].value = gold_text(&gold);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use super::{
+    components::StyledButton,
+    label::{LabelWidget, TextSize},
+    resources::Theme,
+    UiParent,
+};
+
+pub struct ButtonWidget {
+    label: String,
+    size: Option<Vec2>,
+    text_size: TextSize,
+}
+
+impl ButtonWidget {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            size: None,
+            text_size: TextSize::Body,
+        }
+    }
+
+    pub fn size(self, size: Vec2) -> Self {
+        Self {
+            size: Some(size),
+            ..self
+        }
+    }
+
+    pub fn text_size(self, text_size: TextSize) -> Self {
+        Self { text_size, ..self }
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        let size = self.size.unwrap_or(theme.button_size);
+        let mut button = parent.spawn_ui((
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Px(size.x),
+                    height: Val::Px(size.y),
+                    ..default()
+                },
+                background_color: theme.button_bg.into(),
+                ..default()
+            },
+            StyledButton,
+        ));
+        button.with_children(|parent| {
+            LabelWidget::new(self.label)
+                .size(self.text_size)
+                .spawn(parent, theme);
+        });
+        button
+    }
+}
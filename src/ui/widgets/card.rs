@@ -0,0 +1,90 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use super::{
+    components::StyledButton,
+    label::{LabelWidget, TextSize},
+    resources::Theme,
+    UiParent,
+};
+
+pub struct CardWidget {
+    title: String,
+    lines: Vec<String>,
+    icon: Option<Color>,
+    border: Option<Color>,
+}
+
+impl CardWidget {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            lines: Vec::new(),
+            icon: None,
+            border: None,
+        }
+    }
+
+    pub fn line(mut self, line: impl Into<String>) -> Self {
+        self.lines.push(line.into());
+        self
+    }
+
+    pub fn icon(self, color: Color) -> Self {
+        Self {
+            icon: Some(color),
+            ..self
+        }
+    }
+
+    pub fn border(self, color: Color) -> Self {
+        Self {
+            border: Some(color),
+            ..self
+        }
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        let mut card = parent.spawn_ui((
+            ButtonBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(theme.gap),
+                    padding: UiRect::all(Val::Px(12.)),
+                    border: UiRect::all(Val::Px(4.)),
+                    width: Val::Px(theme.card_size.x),
+                    height: Val::Px(theme.card_size.y),
+                    ..default()
+                },
+                background_color: theme.button_bg.into(),
+                border_color: self.border.unwrap_or(theme.button_hover_bg).into(),
+                ..default()
+            },
+            StyledButton,
+        ));
+        card.with_children(|parent| {
+            if let Some(icon) = self.icon {
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(48.),
+                        height: Val::Px(48.),
+                        ..default()
+                    },
+                    background_color: icon.into(),
+                    ..default()
+                });
+            }
+            LabelWidget::new(self.title)
+                .size(TextSize::Body)
+                .spawn(parent, theme);
+            self.lines.into_iter().for_each(|line| {
+                LabelWidget::new(line)
+                    .size(TextSize::Small)
+                    .width(Val::Percent(100.))
+                    .spawn(parent, theme);
+            });
+        });
+        card
+    }
+}
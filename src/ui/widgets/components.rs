@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct StyledButton;
+
+#[derive(Component)]
+pub struct Slider {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+impl Slider {
+    pub fn fraction(&self) -> f32 {
+        ((self.value - self.min) / (self.max - self.min)).clamp(0., 1.)
+    }
+}
+
+#[derive(Component)]
+pub struct SliderFill;
+
+#[derive(Component)]
+pub struct SliderText;
+
+#[derive(Component)]
+pub struct Toggle(pub bool);
@@ -0,0 +1,75 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use super::{resources::Theme, UiParent};
+
+#[derive(Clone, Copy)]
+pub enum TextSize {
+    Title,
+    Heading,
+    Body,
+    Small,
+}
+
+pub struct LabelWidget {
+    text: String,
+    size: TextSize,
+    color: Option<Color>,
+    width: Option<Val>,
+}
+
+impl LabelWidget {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            size: TextSize::Body,
+            color: None,
+            width: None,
+        }
+    }
+
+    pub fn size(self, size: TextSize) -> Self {
+        Self { size, ..self }
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn width(self, width: Val) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    pub fn text_style(size: TextSize, theme: &Theme) -> TextStyle {
+        TextStyle {
+            font_size: match size {
+                TextSize::Title => theme.title_font_size,
+                TextSize::Heading => theme.heading_font_size,
+                TextSize::Body => theme.body_font_size,
+                TextSize::Small => theme.small_font_size,
+            },
+            color: theme.text_color,
+            ..default()
+        }
+    }
+
+    pub fn bundle(self, theme: &Theme) -> TextBundle {
+        let mut style = Self::text_style(self.size, theme);
+        if let Some(color) = self.color {
+            style.color = color;
+        }
+        TextBundle::from_section(self.text, style).with_style(Style {
+            width: self.width.unwrap_or_default(),
+            ..default()
+        })
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        parent.spawn_ui(self.bundle(theme))
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use super::{
+    label::{LabelWidget, TextSize},
+    panel::PanelWidget,
+    resources::Theme,
+    UiParent,
+};
+
+pub struct ListWidget {
+    items: Vec<String>,
+}
+
+impl ListWidget {
+    pub fn new(items: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+        }
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        let mut list = PanelWidget::column().spawn(parent, theme);
+        list.with_children(|parent| {
+            self.items.into_iter().for_each(|item| {
+                LabelWidget::new(item)
+                    .size(TextSize::Heading)
+                    .spawn(parent, theme);
+            });
+        });
+        list
+    }
+}
@@ -0,0 +1,51 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use self::{
+    resources::Theme,
+    systems::{
+        interact_slider, interact_styled_button, interact_toggle, update_slider_visuals,
+        update_toggle_visuals,
+    },
+};
+
+pub mod button;
+pub mod card;
+pub mod components;
+pub mod label;
+pub mod list;
+pub mod panel;
+pub mod resources;
+pub mod slider;
+pub mod systems;
+pub mod toggle;
+
+pub trait UiParent {
+    fn spawn_ui(&mut self, bundle: impl Bundle) -> EntityCommands<'_>;
+}
+
+impl UiParent for Commands<'_, '_> {
+    fn spawn_ui(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
+        self.spawn(bundle)
+    }
+}
+
+impl UiParent for ChildBuilder<'_> {
+    fn spawn_ui(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
+        self.spawn(bundle)
+    }
+}
+
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>().add_systems(
+            Update,
+            (
+                interact_styled_button,
+                (interact_slider, update_slider_visuals).chain(),
+                (interact_toggle, update_toggle_visuals).chain(),
+            ),
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use super::{resources::Theme, UiParent};
+
+pub struct PanelWidget {
+    style: Style,
+    gap: Option<f32>,
+    background: Option<Color>,
+}
+
+impl PanelWidget {
+    pub fn overlay() -> Self {
+        Self {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                padding: UiRect::all(Val::Px(40.)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            gap: None,
+            background: None,
+        }
+    }
+
+    pub fn column() -> Self {
+        Self {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            gap: None,
+            background: None,
+        }
+    }
+
+    pub fn row() -> Self {
+        Self {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            gap: None,
+            background: None,
+        }
+    }
+
+    pub fn gap(self, gap: f32) -> Self {
+        Self {
+            gap: Some(gap),
+            ..self
+        }
+    }
+
+    pub fn justify(mut self, justify: JustifyContent) -> Self {
+        self.style.justify_content = justify;
+        self
+    }
+
+    pub fn position(mut self, position_type: PositionType) -> Self {
+        self.style.position_type = position_type;
+        self
+    }
+
+    pub fn width(mut self, width: Val) -> Self {
+        self.style.width = width;
+        self
+    }
+
+    pub fn background(self, color: Color) -> Self {
+        Self {
+            background: Some(color),
+            ..self
+        }
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        let gap = Val::Px(self.gap.unwrap_or(theme.gap));
+        parent.spawn_ui(NodeBundle {
+            style: Style {
+                row_gap: gap,
+                column_gap: gap,
+                ..self.style
+            },
+            background_color: self.background.unwrap_or(theme.overlay_bg).into(),
+            ..default()
+        })
+    }
+}
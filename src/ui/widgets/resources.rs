@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::gameplay::upgrades::assets::Rarity;
+
+#[derive(Resource, Clone)]
+pub struct Theme {
+    pub text_color: Color,
+    pub accent_color: Color,
+    pub danger_color: Color,
+    pub button_bg: Color,
+    pub button_hover_bg: Color,
+    pub button_press_bg: Color,
+    pub panel_bg: Color,
+    pub overlay_bg: Color,
+    pub common_color: Color,
+    pub uncommon_color: Color,
+    pub rare_color: Color,
+    pub epic_color: Color,
+    pub button_size: Vec2,
+    pub widget_size: Vec2,
+    pub card_size: Vec2,
+    pub title_font_size: f32,
+    pub heading_font_size: f32,
+    pub body_font_size: f32,
+    pub small_font_size: f32,
+    pub gap: f32,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            text_color: Color::WHITE,
+            accent_color: Color::rgb(0.35, 0.75, 0.35),
+            danger_color: Color::RED,
+            button_bg: Color::rgb(0.15, 0.15, 0.15),
+            button_hover_bg: Color::rgb(0.25, 0.25, 0.25),
+            button_press_bg: Color::rgb(0.35, 0.75, 0.35),
+            panel_bg: Color::rgba(0.1, 0.1, 0.1, 0.9),
+            overlay_bg: Color::NONE,
+            common_color: Color::rgb(0.6, 0.6, 0.6),
+            uncommon_color: Color::rgb(0.3, 0.8, 0.3),
+            rare_color: Color::rgb(0.3, 0.5, 1.0),
+            epic_color: Color::rgb(0.7, 0.3, 0.9),
+            button_size: Vec2::new(200., 80.),
+            widget_size: Vec2::new(200., 32.),
+            card_size: Vec2::new(280., 340.),
+            title_font_size: 100.,
+            heading_font_size: 40.,
+            body_font_size: 32.,
+            small_font_size: 20.,
+            gap: 8.,
+        }
+    }
+}
+
+impl Theme {
+    pub fn rarity_color(&self, rarity: Rarity) -> Color {
+        match rarity {
+            Rarity::Common => self.common_color,
+            Rarity::Uncommon => self.uncommon_color,
+            Rarity::Rare => self.rare_color,
+            Rarity::Epic => self.epic_color,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::{ecs::system::EntityCommands, prelude::*, ui::RelativeCursorPosition};
+
+use super::{
+    components::{Slider, SliderFill, SliderText},
+    label::{LabelWidget, TextSize},
+    resources::Theme,
+    UiParent,
+};
+
+pub struct SliderWidget {
+    slider: Slider,
+}
+
+impl SliderWidget {
+    pub fn new(value: f32, min: f32, max: f32) -> Self {
+        Self {
+            slider: Slider {
+                value,
+                min,
+                max,
+                step: (max - min) / 100.,
+            },
+        }
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.slider.step = step;
+        self
+    }
+
+    pub fn fill_style(fraction: f32) -> Style {
+        Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.),
+            width: Val::Percent(fraction * 100.),
+            height: Val::Percent(100.),
+            ..default()
+        }
+    }
+
+    pub fn value_text(value: f32) -> String {
+        format!("{value:.2}")
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        let fraction = self.slider.fraction();
+        let value = self.slider.value;
+        let mut slider = parent.spawn_ui((
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Px(theme.widget_size.x),
+                    height: Val::Px(theme.widget_size.y),
+                    ..default()
+                },
+                background_color: theme.button_bg.into(),
+                ..default()
+            },
+            RelativeCursorPosition::default(),
+            self.slider,
+        ));
+        slider.with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: Self::fill_style(fraction),
+                    background_color: theme.accent_color.into(),
+                    ..default()
+                },
+                SliderFill,
+            ));
+            LabelWidget::new(Self::value_text(value))
+                .size(TextSize::Small)
+                .spawn(parent, theme)
+                .insert(SliderText);
+        });
+        slider
+    }
+}
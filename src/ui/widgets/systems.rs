@@ -0,0 +1,91 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use super::{
+    components::{Slider, SliderFill, SliderText, StyledButton, Toggle},
+    resources::Theme,
+    slider::SliderWidget,
+    toggle::ToggleWidget,
+};
+
+pub fn interact_styled_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<StyledButton>),
+    >,
+    theme: Res<Theme>,
+) {
+    button_query
+        .iter_mut()
+        .for_each(|(interaction, mut bg_color)| match *interaction {
+            Interaction::Pressed => {
+                *bg_color = theme.button_press_bg.into();
+            }
+            Interaction::Hovered => {
+                *bg_color = theme.button_hover_bg.into();
+            }
+            Interaction::None => {
+                *bg_color = theme.button_bg.into();
+            }
+        })
+}
+
+pub fn interact_slider(
+    mut slider_query: Query<(&Interaction, &RelativeCursorPosition, &mut Slider)>,
+) {
+    slider_query
+        .iter_mut()
+        .filter(|(interaction, _, _)| **interaction == Interaction::Pressed)
+        .for_each(|(_, cursor, mut slider)| {
+            if let Some(position) = cursor.normalized {
+                let raw = slider.min + position.x.clamp(0., 1.) * (slider.max - slider.min);
+                let value =
+                    ((raw / slider.step).round() * slider.step).clamp(slider.min, slider.max);
+                if value != slider.value {
+                    slider.value = value;
+                }
+            }
+        });
+}
+
+pub fn update_slider_visuals(
+    slider_query: Query<(&Slider, &Children), Changed<Slider>>,
+    mut fill_query: Query<&mut Style, With<SliderFill>>,
+    mut text_query: Query<&mut Text, With<SliderText>>,
+) {
+    slider_query.iter().for_each(|(slider, children)| {
+        children.iter().for_each(|child| {
+            if let Ok(mut style) = fill_query.get_mut(*child) {
+                *style = SliderWidget::fill_style(slider.fraction());
+            }
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = SliderWidget::value_text(slider.value);
+            }
+        });
+    });
+}
+
+pub fn interact_toggle(mut toggle_query: Query<(&Interaction, &mut Toggle), Changed<Interaction>>) {
+    toggle_query
+        .iter_mut()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .for_each(|(_, mut toggle)| {
+            toggle.0 = !toggle.0;
+        });
+}
+
+pub fn update_toggle_visuals(
+    mut toggle_query: Query<(&Toggle, &Children, &mut BackgroundColor), Changed<Toggle>>,
+    mut text_query: Query<&mut Text>,
+    theme: Res<Theme>,
+) {
+    toggle_query
+        .iter_mut()
+        .for_each(|(toggle, children, mut bg_color)| {
+            *bg_color = ToggleWidget::bg_color(toggle.0, &theme).into();
+            children.iter().for_each(|child| {
+                if let Ok(mut text) = text_query.get_mut(*child) {
+                    text.sections[0].value = ToggleWidget::label(toggle.0).to_string();
+                }
+            });
+        });
+}
@@ -0,0 +1,57 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use super::{
+    components::Toggle,
+    label::{LabelWidget, TextSize},
+    resources::Theme,
+    UiParent,
+};
+
+pub struct ToggleWidget {
+    on: bool,
+}
+
+impl ToggleWidget {
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+
+    pub fn bg_color(on: bool, theme: &Theme) -> Color {
+        if on {
+            theme.accent_color
+        } else {
+            theme.button_bg
+        }
+    }
+
+    pub fn label(on: bool) -> &'static str {
+        if on {
+            "On"
+        } else {
+            "Off"
+        }
+    }
+
+    pub fn spawn<'a>(self, parent: &'a mut impl UiParent, theme: &Theme) -> EntityCommands<'a> {
+        let mut toggle = parent.spawn_ui((
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Px(theme.widget_size.x),
+                    height: Val::Px(theme.widget_size.y),
+                    ..default()
+                },
+                background_color: Self::bg_color(self.on, theme).into(),
+                ..default()
+            },
+            Toggle(self.on),
+        ));
+        toggle.with_children(|parent| {
+            LabelWidget::new(Self::label(self.on))
+                .size(TextSize::Small)
+                .spawn(parent, theme);
+        });
+        toggle
+    }
+}